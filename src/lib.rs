@@ -0,0 +1,40 @@
+//! Software-Renderer und Spiellogik für `game_for_idk`.
+//!
+//! - [`math`]: `Vec3`, `Mat4`, `Quat` und `Transform`
+//! - [`rander`]: OBJ/MTL laden, Kamera, Rasterizer, Partikel, Framebuffer
+//! - [`logik`]: Hitboxen, Kollision, Strahlen, Szenen-Dateien und die Objekt-Welt
+//!
+//! Ein Bild ohne Fenster rendern:
+//!
+//! ```no_run
+//! use std::collections::HashMap;
+//! use rander3d::math::{Transform, Vec3};
+//! use rander3d::rander::kamera::Camera;
+//! use rander3d::rander::offscreen::{render_to_file, Scene, SceneObject};
+//! use rander3d::rander::rander_model::{load_model_materials, load_obj};
+//!
+//! let model = load_obj("assets/cube.obj").unwrap();
+//! let materials = load_model_materials(&model).unwrap_or_else(|_| HashMap::new());
+//!
+//! let mut camera = Camera::new(60.0, 16.0 / 9.0, 0.1, 1000.0);
+//! camera.position = Vec3::new(0.0, 2.0, -5.0);
+//! camera.look_at(Vec3::ZERO);
+//!
+//! let scene = Scene {
+//!     objects: vec![SceneObject { model: &model, transform: Transform::new(Vec3::ZERO), materials: &materials }],
+//!     particles: Vec::new(),
+//!     light_dir: Vec3::new(0.1, 1.0, -0.1),
+//!     strake: 0.5,
+//!     overlay: None,
+//! };
+//! render_to_file(&scene, &camera, 640, 360, "frame.png").unwrap();
+//! ```
+
+#![warn(missing_docs)]
+
+/// Vektoren, Matrizen, Quaternionen und Transforms
+pub mod math;
+/// Software-Renderer
+pub mod rander;
+/// Spiellogik
+pub mod logik;
@@ -0,0 +1,210 @@
+// logik/eingabe.rs
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use minifb::Key;
+use serde::Deserialize;
+use crate::rander::fehler::LoadError;
+
+/// Was der Spieler tun will, unabhängig von der Taste
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum Action {
+    /// Vorwärts laufen
+    MoveForward,
+    /// Rückwärts laufen
+    MoveBack,
+    /// Seitlich nach links
+    StrafeLeft,
+    /// Seitlich nach rechts
+    StrafeRight,
+    /// Springen, im Flugmodus hoch
+    Jump,
+    /// Im Flugmodus runter
+    MoveDown,
+    /// Nach links drehen
+    TurnLeft,
+    /// Nach rechts drehen
+    TurnRight,
+    /// Nach oben schauen
+    LookUp,
+    /// Nach unten schauen
+    LookDown,
+    /// Sichtfeld enger
+    ZoomIn,
+    /// Sichtfeld weiter
+    ZoomOut,
+    /// Partikel beim Spieler ausstoßen
+    FireParticles,
+    /// Gefangene Maus loslassen
+    ReleaseMouse,
+    /// Beenden
+    Quit,
+}
+
+impl Action {
+    /// Alle Aktionen, in Datei-Reihenfolge
+    pub const ALL: [Action; 15] = [
+        Action::MoveForward,
+        Action::MoveBack,
+        Action::StrafeLeft,
+        Action::StrafeRight,
+        Action::Jump,
+        Action::MoveDown,
+        Action::TurnLeft,
+        Action::TurnRight,
+        Action::LookUp,
+        Action::LookDown,
+        Action::ZoomIn,
+        Action::ZoomOut,
+        Action::FireParticles,
+        Action::ReleaseMouse,
+        Action::Quit,
+    ];
+
+    /// Standardbelegung, wenn die Config nichts anderes sagt
+    pub fn default_keys(self) -> Vec<Key> {
+        match self {
+            Action::MoveForward => vec![Key::W],
+            Action::MoveBack => vec![Key::S],
+            Action::StrafeLeft => vec![Key::A],
+            Action::StrafeRight => vec![Key::D],
+            Action::Jump => vec![Key::Space],
+            Action::MoveDown => vec![Key::Q],
+            Action::TurnLeft => vec![Key::Left],
+            Action::TurnRight => vec![Key::Right],
+            Action::LookUp => vec![Key::Up],
+            Action::LookDown => vec![Key::Down],
+            Action::ZoomIn => vec![Key::Equal],
+            Action::ZoomOut => vec![Key::Minus],
+            Action::FireParticles => vec![Key::F],
+            Action::ReleaseMouse => vec![Key::Tab],
+            Action::Quit => vec![Key::Escape],
+        }
+    }
+}
+
+// Alle Tasten von minifb, um Namen aus der Config zu prüfen
+const ALL_KEYS: [Key; 106] = [
+    Key::Key0, Key::Key1, Key::Key2, Key::Key3, Key::Key4, Key::Key5, Key::Key6, Key::Key7, Key::Key8, Key::Key9,
+    Key::A, Key::B, Key::C, Key::D, Key::E, Key::F, Key::G, Key::H, Key::I, Key::J, Key::K, Key::L, Key::M,
+    Key::N, Key::O, Key::P, Key::Q, Key::R, Key::S, Key::T, Key::U, Key::V, Key::W, Key::X, Key::Y, Key::Z,
+    Key::F1, Key::F2, Key::F3, Key::F4, Key::F5, Key::F6, Key::F7, Key::F8, Key::F9, Key::F10, Key::F11,
+    Key::F12, Key::F13, Key::F14, Key::F15,
+    Key::Down, Key::Left, Key::Right, Key::Up, Key::Apostrophe, Key::Backquote, Key::Backslash, Key::Comma,
+    Key::Equal, Key::LeftBracket, Key::Minus, Key::Period, Key::RightBracket, Key::Semicolon, Key::Slash,
+    Key::Backspace, Key::Delete, Key::End, Key::Enter, Key::Escape, Key::Home, Key::Insert, Key::Menu,
+    Key::PageDown, Key::PageUp, Key::Pause, Key::Space, Key::Tab, Key::NumLock, Key::CapsLock, Key::ScrollLock,
+    Key::LeftShift, Key::RightShift, Key::LeftCtrl, Key::RightCtrl,
+    Key::NumPad0, Key::NumPad1, Key::NumPad2, Key::NumPad3, Key::NumPad4, Key::NumPad5, Key::NumPad6,
+    Key::NumPad7, Key::NumPad8, Key::NumPad9, Key::NumPadDot, Key::NumPadSlash, Key::NumPadAsterisk,
+    Key::NumPadMinus, Key::NumPadPlus, Key::NumPadEnter,
+    Key::LeftAlt, Key::RightAlt, Key::LeftSuper, Key::RightSuper,
+];
+
+/// Taste nach ihrem Namen in minifb ("W", "Space", "LeftShift", "Key1", ...)
+pub fn key_from_name(name: &str) -> Option<Key> {
+    ALL_KEYS.iter().copied().find(|k| format!("{:?}", k) == name)
+}
+
+/// Tastenbelegung und Mauseinstellungen
+#[derive(Clone, Debug)]
+pub struct InputConfig {
+    /// Tasten pro Aktion, jede davon löst aus
+    pub bindings: HashMap<Action, Vec<Key>>,
+    /// Drehung in rad pro Pixel
+    pub mouse_sensitivity: f32,
+    /// Maus-y umkehren
+    pub invert_mouse: bool,
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        InputConfig {
+            bindings: Action::ALL.iter().map(|&a| (a, a.default_keys())).collect(),
+            mouse_sensitivity: 0.003,
+            invert_mouse: false,
+        }
+    }
+}
+
+// So steht es in der Datei; alles ist optional
+#[derive(Deserialize)]
+#[serde(default)]
+struct InputFile {
+    bindings: HashMap<Action, Vec<String>>,
+    mouse_sensitivity: f32,
+    invert_mouse: bool,
+}
+
+impl Default for InputFile {
+    fn default() -> Self {
+        let config = InputConfig::default();
+        InputFile {
+            bindings: HashMap::new(),
+            mouse_sensitivity: config.mouse_sensitivity,
+            invert_mouse: config.invert_mouse,
+        }
+    }
+}
+
+/// Lädt die Belegung aus einer RON-Datei. Nicht genannte Aktionen
+/// behalten ihre Standardtasten, `[]` nimmt einer Aktion alle Tasten.
+pub fn load_input_config(path: &str) -> Result<InputConfig, LoadError> {
+    let text = fs::read_to_string(path).map_err(|e| LoadError::new(path, None, format!("Konnte Tastenbelegung nicht öffnen: {}", e)))?;
+    let file: InputFile = ron::from_str(&text)
+        .map_err(|e| LoadError::at(path, e.position.line, e.code.to_string()))?;
+
+    let mut config = InputConfig {
+        mouse_sensitivity: file.mouse_sensitivity,
+        invert_mouse: file.invert_mouse,
+        ..InputConfig::default()
+    };
+    for (action, names) in file.bindings {
+        let keys = names
+            .iter()
+            .map(|n| key_from_name(n).ok_or_else(|| LoadError::new(path, None, format!("unbekannte Taste '{}' für {:?}", n, action))))
+            .collect::<Result<_, _>>()?;
+        config.bindings.insert(action, keys);
+    }
+    Ok(config)
+}
+
+/// Zustand der Aktionen im aktuellen Bild
+pub struct Input {
+    /// Aktuelle Belegung
+    pub config: InputConfig,
+    down: HashSet<Action>,
+    before: HashSet<Action>,
+}
+
+impl Input {
+    /// Noch nichts gedrückt
+    pub fn new(config: InputConfig) -> Self {
+        Input { config, down: HashSet::new(), before: HashSet::new() }
+    }
+
+    /// Einmal pro Bild mit den gedrückten Tasten aufrufen
+    pub fn update(&mut self, keys: &[Key]) {
+        self.before = std::mem::take(&mut self.down);
+        for (action, bound) in &self.config.bindings {
+            if bound.iter().any(|k| keys.contains(k)) {
+                self.down.insert(*action);
+            }
+        }
+    }
+
+    /// In diesem Bild gedrückt
+    pub fn is_down(&self, action: Action) -> bool {
+        self.down.contains(&action)
+    }
+
+    /// Erst in diesem Bild gedrückt
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.down.contains(&action) && !self.before.contains(&action)
+    }
+
+    /// -1, 0 oder 1 für ein Paar gegensätzlicher Aktionen
+    pub fn axis(&self, negative: Action, positive: Action) -> f32 {
+        self.is_down(positive) as i32 as f32 - self.is_down(negative) as i32 as f32
+    }
+}
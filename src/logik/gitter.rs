@@ -0,0 +1,217 @@
+// logik/gitter.rs
+
+use std::collections::{HashMap, HashSet};
+use crate::math::Vec3;
+use crate::logik::hitbox::{check_aabb_collision, AABB};
+use crate::logik::welt::Entity;
+
+// Objekte über so viele Zellen kommen in `large` und werden immer geprüft
+const MAX_CELLS: i64 = 4096;
+
+type Cell = (i32, i32, i32);
+
+/// Broad-Phase: gleichmäßiges Raster über die Welt. Jede Hülle steht in allen
+/// Zellen, die sie berührt, Anfragen sehen nur die Objekte in ihren Zellen.
+pub struct SpatialHash {
+    cell_size: f32,
+    cells: HashMap<Cell, Vec<Entity>>,
+    large: Vec<Entity>,
+    bounds: HashMap<Entity, (AABB, Option<(Cell, Cell)>)>, // None = in `large`
+    occupied: Option<AABB>, // umschließt alle Zellen-Einträge, wird nur beim Leeren kleiner
+}
+
+impl SpatialHash {
+    /// Leeres Raster mit Zellen der Kantenlänge `cell_size`
+    pub fn new(cell_size: f32) -> Self {
+        SpatialHash { cell_size, cells: HashMap::new(), large: Vec::new(), bounds: HashMap::new(), occupied: None }
+    }
+
+    /// Anzahl eingetragener Objekte
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    /// Nichts eingetragen?
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    /// Hülle von `entity` in Weltkoordinaten
+    pub fn bounds(&self, entity: Entity) -> Option<AABB> {
+        self.bounds.get(&entity).map(|(b, _)| *b)
+    }
+
+    fn cell(&self, p: Vec3) -> Cell {
+        let c = |v: f32| (v / self.cell_size).floor() as i32;
+        (c(p.x), c(p.y), c(p.z))
+    }
+
+    fn range(&self, aabb: &AABB) -> (Cell, Cell) {
+        (self.cell(aabb.min), self.cell(aabb.max))
+    }
+
+    fn cells_in((lo, hi): (Cell, Cell)) -> impl Iterator<Item = Cell> {
+        (lo.0..=hi.0).flat_map(move |x| (lo.1..=hi.1).flat_map(move |y| (lo.2..=hi.2).map(move |z| (x, y, z))))
+    }
+
+    /// Einsortieren oder, wenn schon drin, neu einsortieren
+    pub fn insert(&mut self, entity: Entity, aabb: AABB) {
+        self.remove(entity);
+
+        let (lo, hi) = self.range(&aabb);
+        let count = (hi.0 as i64 - lo.0 as i64 + 1) * (hi.1 as i64 - lo.1 as i64 + 1) * (hi.2 as i64 - lo.2 as i64 + 1);
+        if count > MAX_CELLS {
+            self.large.push(entity);
+            self.bounds.insert(entity, (aabb, None));
+            return;
+        }
+        for cell in Self::cells_in((lo, hi)) {
+            self.cells.entry(cell).or_default().push(entity);
+        }
+        self.bounds.insert(entity, (aabb, Some((lo, hi))));
+        self.occupied = Some(match self.occupied {
+            Some(o) => AABB { min: o.min.min(aabb.min), max: o.max.max(aabb.max) },
+            None => aabb,
+        });
+    }
+
+    /// Austragen, `false` wenn es nicht drin war
+    pub fn remove(&mut self, entity: Entity) -> bool {
+        let Some((_, range)) = self.bounds.remove(&entity) else {
+            return false;
+        };
+        match range {
+            Some(range) => {
+                for cell in Self::cells_in(range) {
+                    if let Some(list) = self.cells.get_mut(&cell) {
+                        list.retain(|e| *e != entity);
+                        if list.is_empty() {
+                            self.cells.remove(&cell);
+                        }
+                    }
+                }
+            }
+            None => self.large.retain(|e| *e != entity),
+        }
+        if self.cells.is_empty() {
+            self.occupied = None;
+        }
+        true
+    }
+
+    /// Alle Objekte, deren Hülle `region` berührt, nach Id sortiert
+    pub fn query(&self, region: &AABB) -> Vec<Entity> {
+        let (lo, hi) = self.range(region);
+        let count = (hi.0 as i64 - lo.0 as i64 + 1) * (hi.1 as i64 - lo.1 as i64 + 1) * (hi.2 as i64 - lo.2 as i64 + 1);
+        // riesige Region: schneller alles durchgehen als alle Zellen
+        let candidates: Box<dyn Iterator<Item = Entity>> = if count > self.cells.len() as i64 {
+            Box::new(self.bounds.keys().copied())
+        } else {
+            Box::new(Self::cells_in((lo, hi)).filter_map(|c| self.cells.get(&c)).flatten().chain(&self.large).copied())
+        };
+
+        let mut found: Vec<Entity> = candidates
+            .filter(|e| check_aabb_collision(&self.bounds[e].0, region, Vec3::ZERO, Vec3::ZERO))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        found.sort_by_key(|e| e.0);
+        found
+    }
+
+    /// Objekte in den Zellen entlang eines Strahls bis `max_distance`
+    /// (3D-DDA nach Amanatides & Woo). Nur Kandidaten, der genaue Test kommt danach.
+    /// Gelaufen wird nur durch den belegten Bereich, `max_distance` darf unendlich sein.
+    pub fn query_ray(&self, origin: Vec3, dir: Vec3, max_distance: f32) -> Vec<Entity> {
+        let mut found: HashSet<Entity> = self.large.iter().copied().collect();
+
+        // Strahl auf den belegten Bereich kürzen
+        let span = self.occupied.and_then(|o| clip_ray(origin, dir, &o, max_distance));
+        if let Some((enter, exit)) = span {
+            let size = self.cell_size;
+            let mut cell = self.cell(origin + dir * enter);
+            let axis = |o: f32, d: f32, c: i32| {
+                if d > 0.0 {
+                    (1, ((c + 1) as f32 * size - o) / d, size / d)
+                } else if d < 0.0 {
+                    (-1, (c as f32 * size - o) / d, -size / d)
+                } else {
+                    (0, f32::INFINITY, f32::INFINITY)
+                }
+            };
+            let (sx, mut tx, dx) = axis(origin.x, dir.x, cell.0);
+            let (sy, mut ty, dy) = axis(origin.y, dir.y, cell.1);
+            let (sz, mut tz, dz) = axis(origin.z, dir.z, cell.2);
+
+            // jede Zelle auf der Strecke wird über genau eine Achse betreten
+            let steps = (((exit - enter) / size) as usize + 2) * 3;
+            for _ in 0..steps {
+                if let Some(list) = self.cells.get(&cell) {
+                    found.extend(list);
+                }
+                let t = tx.min(ty).min(tz);
+                if t > exit {
+                    break;
+                }
+                if t == tx {
+                    cell.0 += sx;
+                    tx += dx;
+                } else if t == ty {
+                    cell.1 += sy;
+                    ty += dy;
+                } else {
+                    cell.2 += sz;
+                    tz += dz;
+                }
+            }
+        }
+
+        let mut found: Vec<Entity> = found.into_iter().collect();
+        found.sort_by_key(|e| e.0);
+        found
+    }
+
+    /// Alle Paare mit sich berührenden Hüllen, jedes einmal und (kleinere Id, größere Id)
+    pub fn pairs(&self) -> Vec<(Entity, Entity)> {
+        let mut pairs = HashSet::new();
+        let mut add = |a: Entity, b: Entity| {
+            if a != b && check_aabb_collision(&self.bounds[&a].0, &self.bounds[&b].0, Vec3::ZERO, Vec3::ZERO) {
+                pairs.insert(if a.0 < b.0 { (a, b) } else { (b, a) });
+            }
+        };
+        for list in self.cells.values() {
+            for (i, a) in list.iter().enumerate() {
+                for b in &list[i + 1..] {
+                    add(*a, *b);
+                }
+            }
+        }
+        for a in &self.large {
+            for b in self.bounds.keys() {
+                add(*a, *b);
+            }
+        }
+
+        let mut pairs: Vec<(Entity, Entity)> = pairs.into_iter().collect();
+        pairs.sort_by_key(|(a, b)| (a.0, b.0));
+        pairs
+    }
+}
+
+// Teil 0..max_distance des Strahls, der in `aabb` liegt, als (Eintritt, Austritt)
+fn clip_ray(origin: Vec3, dir: Vec3, aabb: &AABB, max_distance: f32) -> Option<(f32, f32)> {
+    let axes = [(origin.x, dir.x, aabb.min.x, aabb.max.x), (origin.y, dir.y, aabb.min.y, aabb.max.y), (origin.z, dir.z, aabb.min.z, aabb.max.z)];
+    let (mut enter, mut exit) = (0.0f32, max_distance);
+    for (o, d, min, max) in axes {
+        if d == 0.0 {
+            if o < min || o > max {
+                return None;
+            }
+            continue;
+        }
+        let (t0, t1) = ((min - o) / d, (max - o) / d);
+        enter = enter.max(t0.min(t1));
+        exit = exit.min(t0.max(t1));
+    }
+    (enter <= exit).then_some((enter, exit))
+}
@@ -1,60 +1,468 @@
 // logik/hitbox.rs
 
-use crate::rander::rander_model::{Vec3, load_obj};
+use crate::math::{Quat, Transform, Vec3};
+use crate::rander::rander_model::{load_obj, Model};
+use crate::rander::fehler::LoadError;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Achsenparallele Box
 pub struct AABB {
+    /// Kleinste Ecke
     pub min: Vec3,
+    /// Größte Ecke
     pub max: Vec3,
 }
 
-pub fn load_obj_hitbox(path: &str) -> Option<AABB> {
-    let model = load_obj(path);
+impl AABB {
+    /// Mittelpunkt
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Alle acht Ecken, Bit 0/1/2 des Index wählt max bei x/y/z
+    pub fn corners(&self) -> [Vec3; 8] {
+        let (a, b) = (self.min, self.max);
+        std::array::from_fn(|i| {
+            Vec3::new(
+                if i & 1 == 0 { a.x } else { b.x },
+                if i & 2 == 0 { a.y } else { b.y },
+                if i & 4 == 0 { a.z } else { b.z },
+            )
+        })
+    }
+
+    /// Achsenparallele Hülle der mit `t` verschobenen, gedrehten und
+    /// skalierten Box, in Weltkoordinaten
+    pub fn transformed(&self, t: &Transform) -> AABB {
+        let m = t.matrix();
+        let corners = self.corners().map(|c| m.transform_point(c));
+        let (min, max) = corners[1..].iter().fold((corners[0], corners[0]), |(min, max), c| (min.min(*c), max.max(*c)));
+        AABB { min, max }
+    }
+}
+
+/// Gedrehte Box (oriented bounding box) in Weltkoordinaten
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OBB {
+    /// Mittelpunkt
+    pub center: Vec3,
+    /// Lokale x, y, z nach der Drehung, Länge 1
+    pub axes: [Vec3; 3],
+    /// Halbe Kantenlängen entlang `axes`
+    pub half: Vec3,
+}
+
+impl OBB {
+    /// Hitbox `aabb` mit dem Transform ihres Objekts
+    pub fn new(aabb: &AABB, t: &Transform) -> OBB {
+        let half = (aabb.max - aabb.min) * 0.5;
+        OBB {
+            center: t.matrix().transform_point(aabb.center()),
+            axes: [Vec3::new(1.0, 0.0, 0.0), Vec3::UP, Vec3::new(0.0, 0.0, 1.0)].map(|a| t.rotation.rotate(a)),
+            half: half.mul_elem(t.scale).abs(),
+        }
+    }
+}
+
+/// Dreht `rotation` nur um Vielfache von 90°? Dann bleibt jede Box achsenparallel.
+pub fn is_axis_aligned(rotation: Quat) -> bool {
+    [Vec3::new(1.0, 0.0, 0.0), Vec3::UP, Vec3::new(0.0, 0.0, 1.0)].iter().all(|a| {
+        let r = rotation.rotate(*a).abs();
+        r.x.max(r.y).max(r.z) > 1.0 - 1e-5
+    })
+}
+
+/// Separating Axis Test für zwei gedrehte Boxen; Berühren zählt wie bei
+/// `check_aabb_collision` als Kollision
+pub fn check_obb_collision(a: &OBB, b: &OBB) -> bool {
+    let d = b.center - a.center;
+    let radius = |o: &OBB, axis: Vec3| {
+        o.axes[0].dot(axis).abs() * o.half.x + o.axes[1].dot(axis).abs() * o.half.y + o.axes[2].dot(axis).abs() * o.half.z
+    };
+
+    // 3 + 3 Flächennormalen und 9 Kanten-Kreuzprodukte
+    let faces = a.axes.iter().chain(b.axes.iter()).copied();
+    let edges = a.axes.iter().flat_map(|x| b.axes.iter().map(move |y| x.cross(*y)));
+    faces
+        .chain(edges)
+        // parallele Kanten ergeben keine Achse
+        .filter(|axis| axis.length_squared() > 1e-6)
+        .all(|axis| d.dot(axis).abs() <= radius(a, axis) + radius(b, axis))
+}
+
+/// Kollision zweier Hitboxen mit den Transforms ihrer Objekte: achsenparallel
+/// über die AABBs, sonst über OBBs
+pub fn check_collision(a: &AABB, a_t: &Transform, b: &AABB, b_t: &Transform) -> bool {
+    if is_axis_aligned(a_t.rotation) && is_axis_aligned(b_t.rotation) {
+        check_aabb_collision(&a.transformed(a_t), &b.transformed(b_t), Vec3::ZERO, Vec3::ZERO)
+    } else {
+        check_obb_collision(&OBB::new(a, a_t), &OBB::new(b, b_t))
+    }
+}
+
+/// Hitbox aus den Eckpunkten einer OBJ-Datei
+pub fn load_obj_hitbox(path: &str) -> Result<AABB, LoadError> {
+    let model = load_obj(path)?;
 
     if model.vertices.is_empty() {
-        return None;
+        return Err(LoadError::new(path, None, "keine Vertices für die Hitbox"));
     }
 
     let mut min = model.vertices[0];
     let mut max = model.vertices[0];
 
     for v in &model.vertices[1..] {
-        min.x = min.x.min(v.x);
-        min.y = min.y.min(v.y);
-        min.z = min.z.min(v.z);
-
-        max.x = max.x.max(v.x);
-        max.y = max.y.max(v.y);
-        max.z = max.z.max(v.z);
+        min = min.min(*v);
+        max = max.max(*v);
     }
 
-    Some(AABB { min, max })
+    Ok(AABB { min, max })
 }
 
-pub fn check_aabb_collision(a: &AABB, b: &AABB, a_pos: (f32, f32, f32), b_pos: (f32, f32, f32)) -> bool {
-    let a_min = Vec3 {
-        x: a.min.x + a_pos.0,
-        y: a.min.y + a_pos.1,
-        z: a.min.z + a_pos.2,
-    };
-    let a_max = Vec3 {
-        x: a.max.x + a_pos.0,
-        y: a.max.y + a_pos.1,
-        z: a.max.z + a_pos.2,
-    };
+/// Überlappen sich zwei Hitboxen an den gegebenen Positionen?
+pub fn check_aabb_collision(a: &AABB, b: &AABB, a_pos: Vec3, b_pos: Vec3) -> bool {
+    let a_min = a.min + a_pos;
+    let a_max = a.max + a_pos;
 
-    let b_min = Vec3 {
-        x: b.min.x + b_pos.0,
-        y: b.min.y + b_pos.1,
-        z: b.min.z + b_pos.2,
-    };
-    let b_max = Vec3 {
-        x: b.max.x + b_pos.0,
-        y: b.max.y + b_pos.1,
-        z: b.max.z + b_pos.2,
-    };
+    let b_min = b.min + b_pos;
+    let b_max = b.max + b_pos;
 
     a_min.x <= b_max.x && a_max.x >= b_min.x &&
     a_min.y <= b_max.y && a_max.y >= b_min.y &&
     a_min.z <= b_max.z && a_max.z >= b_min.z
 }
+
+/// Abstand, der nach einem Kontakt bleibt, damit Berühren nicht als Überlappen zählt
+pub const SKIN: f32 = 1e-3;
+
+/// Erster Kontakt einer bewegten Hitbox
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SweepHit {
+    /// Anteil der Bewegung bis zum Kontakt, 0..1
+    pub time: f32,
+    /// Zeigt von `b` weg, zur bewegten Hitbox hin
+    pub normal: Vec3,
+}
+
+/// `a` bei `a_pos` um `delta` bewegen und den ersten Kontakt mit `b` finden.
+/// Wer schon in `b` steckt, bekommt keinen Treffer und kann hinauslaufen.
+pub fn sweep_aabb(a: &AABB, b: &AABB, a_pos: Vec3, b_pos: Vec3, delta: Vec3) -> Option<SweepHit> {
+    let a_min = a.min + a_pos;
+    let a_max = a.max + a_pos;
+    let b_min = b.min + b_pos;
+    let b_max = b.max + b_pos;
+
+    let axes = [
+        (a_min.x, a_max.x, b_min.x, b_max.x, delta.x, Vec3::new(1.0, 0.0, 0.0)),
+        (a_min.y, a_max.y, b_min.y, b_max.y, delta.y, Vec3::new(0.0, 1.0, 0.0)),
+        (a_min.z, a_max.z, b_min.z, b_max.z, delta.z, Vec3::new(0.0, 0.0, 1.0)),
+    ];
+
+    let mut entry = f32::MIN;
+    let mut exit = f32::MAX;
+    let mut normal = Vec3::ZERO;
+
+    for (a0, a1, b0, b1, d, axis) in axes {
+        let (t0, t1) = if d > 0.0 {
+            ((b0 - a1) / d, (b1 - a0) / d)
+        } else if d < 0.0 {
+            ((b1 - a0) / d, (b0 - a1) / d)
+        } else if a1 <= b0 || a0 >= b1 {
+            // auf dieser Achse getrennt und ohne Bewegung: nie ein Kontakt
+            return None;
+        } else {
+            continue;
+        };
+
+        if t0 > entry {
+            entry = t0;
+            normal = if d > 0.0 { -axis } else { axis };
+        }
+        exit = exit.min(t1);
+    }
+
+    if entry > exit || !(0.0..=1.0).contains(&entry) {
+        return None;
+    }
+    Some(SweepHit { time: entry, normal })
+}
+
+/// Bewegung Achse für Achse (x, z, y) gegen `obstacles` auflösen: auf jeder Achse
+/// bis zum ersten Kontakt gehen, der Rest der Bewegung auf den anderen Achsen
+/// bleibt – so gleitet man an Wänden entlang. Gibt die neue Position und alle
+/// Kontakte als (Index in `obstacles`, Treffer) zurück.
+pub fn resolve_movement(a: &AABB, pos: Vec3, delta: Vec3, obstacles: &[(AABB, Vec3)]) -> (Vec3, Vec<(usize, SweepHit)>) {
+    let mut pos = pos;
+    let mut contacts = Vec::new();
+
+    let steps = [
+        Vec3::new(delta.x, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, delta.z),
+        Vec3::new(0.0, delta.y, 0.0),
+    ];
+    for step in steps {
+        let length = step.length();
+        if length == 0.0 {
+            continue;
+        }
+
+        let hits: Vec<(usize, SweepHit)> = obstacles
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (b, b_pos))| Some((i, sweep_aabb(a, b, pos, *b_pos, step)?)))
+            .collect();
+
+        match hits.iter().map(|(_, h)| h.time).reduce(f32::min) {
+            Some(first) => {
+                // bis kurz vor den Kontakt; gleichzeitige Treffer zählen alle
+                pos += step * ((first * length - SKIN).max(0.0) / length);
+                contacts.extend(hits.into_iter().filter(|(_, h)| h.time <= first + 1e-6));
+            }
+            None => pos += step,
+        }
+    }
+
+    (pos, contacts)
+}
+
+// 🔵 Weitere Formen
+
+/// Form einer Hitbox in Objektkoordinaten. Jede Form wird mit dem Transform
+/// ihres Objekts verschoben, gedreht und skaliert.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Collider {
+    /// Box, gedreht wird daraus eine OBB
+    Box(AABB),
+    /// Kugel
+    Sphere {
+        /// Mittelpunkt
+        center: Vec3,
+        /// Radius, wächst mit der größten Skalierung
+        radius: f32,
+    },
+    /// Strecke `a`–`b` mit Radius, gut für Figuren
+    Capsule {
+        /// Ein Ende der Strecke
+        a: Vec3,
+        /// Anderes Ende der Strecke
+        b: Vec3,
+        /// Radius, wächst mit der größten Skalierung
+        radius: f32,
+    },
+    /// Genau die Dreiecke eines Modells; zählt nur die Oberfläche
+    Mesh(Vec<[Vec3; 3]>),
+}
+
+// Eine Form in Weltkoordinaten
+enum Shape {
+    Sphere(Vec3, f32),
+    Capsule(Vec3, Vec3, f32),
+    Box(OBB),
+    Mesh(Vec<[Vec3; 3]>),
+}
+
+impl Collider {
+    /// Dreiecke aus den Faces eines Modells, Vielecke als Fächer
+    pub fn from_model(model: &Model) -> Option<Collider> {
+        let tris: Vec<[Vec3; 3]> = model
+            .faces
+            .iter()
+            .flat_map(|f| {
+                let v: Vec<Vec3> = f.vertices.iter().filter_map(|fv| model.vertices.get(fv.vertex_index).copied()).collect();
+                (1..v.len().saturating_sub(1)).map(move |i| [v[0], v[i], v[i + 1]])
+            })
+            // Dreiecke ohne Fläche haben keine Normale
+            .filter(|[a, b, c]| (*b - *a).cross(*c - *a).length_squared() > 1e-12)
+            .collect();
+        (!tris.is_empty()).then_some(Collider::Mesh(tris))
+    }
+
+    /// Achsenparallele Hülle in Weltkoordinaten
+    pub fn bounds(&self, t: &Transform) -> AABB {
+        let m = t.matrix();
+        let r = max_scale(t);
+        match self {
+            Collider::Box(b) => b.transformed(t),
+            Collider::Sphere { center, radius } => {
+                let c = m.transform_point(*center);
+                AABB { min: c - Vec3::splat(radius * r), max: c + Vec3::splat(radius * r) }
+            }
+            Collider::Capsule { a, b, radius } => {
+                let (a, b) = (m.transform_point(*a), m.transform_point(*b));
+                AABB { min: a.min(b) - Vec3::splat(radius * r), max: a.max(b) + Vec3::splat(radius * r) }
+            }
+            Collider::Mesh(tris) => points_bounds(tris.iter().flatten().map(|p| m.transform_point(*p))),
+        }
+    }
+
+    fn shape(&self, t: &Transform) -> Shape {
+        let m = t.matrix();
+        let r = max_scale(t);
+        match self {
+            Collider::Box(b) => Shape::Box(OBB::new(b, t)),
+            Collider::Sphere { center, radius } => Shape::Sphere(m.transform_point(*center), radius * r),
+            Collider::Capsule { a, b, radius } => Shape::Capsule(m.transform_point(*a), m.transform_point(*b), radius * r),
+            Collider::Mesh(tris) => Shape::Mesh(tris.iter().map(|tri| tri.map(|p| m.transform_point(p))).collect()),
+        }
+    }
+
+    /// Berühren oder überlappen sich die beiden Formen mit ihren Transforms?
+    pub fn intersects(&self, a_t: &Transform, other: &Collider, b_t: &Transform) -> bool {
+        if let (Collider::Box(a), Collider::Box(b)) = (self, other) {
+            return check_collision(a, a_t, b, b_t);
+        }
+        // zuerst die Hüllen, das ist billig
+        if !check_aabb_collision(&self.bounds(a_t), &other.bounds(b_t), Vec3::ZERO, Vec3::ZERO) {
+            return false;
+        }
+
+        match (self.shape(a_t), other.shape(b_t)) {
+            (Shape::Sphere(c, r), s) | (s, Shape::Sphere(c, r)) => s.distance(c) <= r,
+            (Shape::Capsule(a, b, r), Shape::Mesh(tris)) | (Shape::Mesh(tris), Shape::Capsule(a, b, r)) => {
+                tris.iter().any(|tri| segment_distance(a, b, |p| (p - closest_on_triangle(p, tri)).length()) <= r)
+            }
+            (Shape::Capsule(a, b, r), s) | (s, Shape::Capsule(a, b, r)) => segment_distance(a, b, |p| s.distance(p)) <= r,
+            (Shape::Box(o), Shape::Mesh(tris)) | (Shape::Mesh(tris), Shape::Box(o)) => {
+                let corners = o.corners();
+                tris.iter().any(|tri| box_touches_triangle(&o, &corners, tri))
+            }
+            (Shape::Mesh(a), Shape::Mesh(b)) => a.iter().any(|ta| {
+                let bounds = points_bounds(ta.iter().copied());
+                b.iter().any(|tb| {
+                    check_aabb_collision(&bounds, &points_bounds(tb.iter().copied()), Vec3::ZERO, Vec3::ZERO)
+                        && triangles_touch(ta, tb)
+                })
+            }),
+            (Shape::Box(a), Shape::Box(b)) => check_obb_collision(&a, &b),
+        }
+    }
+}
+
+impl Shape {
+    // Abstand eines Punkts zur Form, 0 innen
+    fn distance(&self, p: Vec3) -> f32 {
+        match self {
+            Shape::Sphere(c, r) => ((p - *c).length() - r).max(0.0),
+            Shape::Capsule(a, b, r) => ((p - closest_on_segment(p, *a, *b)).length() - r).max(0.0),
+            Shape::Box(o) => {
+                let d = p - o.center;
+                let half = [o.half.x, o.half.y, o.half.z];
+                let q = (0..3).fold(o.center, |q, i| q + o.axes[i] * d.dot(o.axes[i]).clamp(-half[i], half[i]));
+                (p - q).length()
+            }
+            Shape::Mesh(tris) => tris.iter().map(|tri| (p - closest_on_triangle(p, tri)).length()).fold(f32::MAX, f32::min),
+        }
+    }
+}
+
+impl OBB {
+    /// Alle acht Ecken in Weltkoordinaten
+    pub fn corners(&self) -> [Vec3; 8] {
+        std::array::from_fn(|i| {
+            let s = |bit: usize| if i & bit == 0 { -1.0 } else { 1.0 };
+            self.center + self.axes[0] * (self.half.x * s(1)) + self.axes[1] * (self.half.y * s(2)) + self.axes[2] * (self.half.z * s(4))
+        })
+    }
+}
+
+fn max_scale(t: &Transform) -> f32 {
+    let s = t.scale.abs();
+    s.x.max(s.y).max(s.z)
+}
+
+fn points_bounds(mut points: impl Iterator<Item = Vec3>) -> AABB {
+    let first = points.next().unwrap_or(Vec3::ZERO);
+    let (min, max) = points.fold((first, first), |(min, max), p| (min.min(p), max.max(p)));
+    AABB { min, max }
+}
+
+fn closest_on_segment(p: Vec3, a: Vec3, b: Vec3) -> Vec3 {
+    let ab = b - a;
+    let len = ab.length_squared();
+    if len == 0.0 {
+        return a;
+    }
+    a + ab * ((p - a).dot(ab) / len).clamp(0.0, 1.0)
+}
+
+// Nach Ericson, "Real-Time Collision Detection", 5.1.5
+fn closest_on_triangle(p: Vec3, [a, b, c]: &[Vec3; 3]) -> Vec3 {
+    let (a, b, c) = (*a, *b, *c);
+    let (ab, ac, ap) = (b - a, c - a, p - a);
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+    let bp = p - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+    let cp = p - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+    let denom = 1.0 / (va + vb + vc);
+    a + ab * (vb * denom) + ac * (vc * denom)
+}
+
+// Kleinster Wert von `dist` entlang der Strecke a–b; `dist` muss konvex sein
+// (Abstand zu einer konvexen Form), dann reicht eine ternäre Suche
+fn segment_distance(a: Vec3, b: Vec3, dist: impl Fn(Vec3) -> f32) -> f32 {
+    let (mut lo, mut hi) = (0.0f32, 1.0f32);
+    for _ in 0..40 {
+        let m1 = lo + (hi - lo) / 3.0;
+        let m2 = hi - (hi - lo) / 3.0;
+        if dist(a.lerp(b, m1)) <= dist(a.lerp(b, m2)) {
+            hi = m2;
+        } else {
+            lo = m1;
+        }
+    }
+    dist(a.lerp(b, (lo + hi) / 2.0))
+}
+
+// SAT für zwei konvexe Punktmengen: keine der Achsen trennt sie
+fn convex_touch(axes: impl Iterator<Item = Vec3>, a: &[Vec3], b: &[Vec3]) -> bool {
+    let range = |pts: &[Vec3], axis: Vec3| {
+        pts.iter().map(|p| p.dot(axis)).fold((f32::MAX, f32::MIN), |(lo, hi), d| (lo.min(d), hi.max(d)))
+    };
+    axes.filter(|axis| axis.length_squared() > 1e-12).all(|axis| {
+        let (a0, a1) = range(a, axis);
+        let (b0, b1) = range(b, axis);
+        a1 >= b0 && b1 >= a0
+    })
+}
+
+fn edges(tri: &[Vec3; 3]) -> [Vec3; 3] {
+    [tri[1] - tri[0], tri[2] - tri[1], tri[0] - tri[2]]
+}
+
+fn box_touches_triangle(o: &OBB, corners: &[Vec3; 8], tri: &[Vec3; 3]) -> bool {
+    let e = edges(tri);
+    let normal = e[0].cross(e[1]);
+    let crosses = o.axes.iter().flat_map(|x| e.iter().map(move |y| x.cross(*y)));
+    convex_touch(o.axes.iter().copied().chain([normal]).chain(crosses), corners, tri)
+}
+
+fn triangles_touch(a: &[Vec3; 3], b: &[Vec3; 3]) -> bool {
+    let (ea, eb) = (edges(a), edges(b));
+    let (na, nb) = (ea[0].cross(ea[1]), eb[0].cross(eb[1]));
+    let crosses = ea.iter().flat_map(|x| eb.iter().map(move |y| x.cross(*y)));
+    // für Dreiecke in derselben Ebene: Achsen in der Ebene
+    let in_plane = ea.map(|e| na.cross(e)).into_iter().chain(eb.map(|e| nb.cross(e)));
+    convex_touch([na, nb].into_iter().chain(crosses).chain(in_plane), a, b)
+}
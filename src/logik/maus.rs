@@ -0,0 +1,61 @@
+// logik/maus.rs
+
+use crate::rander::kamera::Camera;
+
+/// Umsehen mit der Maus. minifb kann den Zeiger nicht festhalten, deshalb
+/// zählt nur die Bewegung zwischen zwei Bildern; "gefangen" heißt: Zeiger
+/// versteckt und Bewegung wird ausgewertet.
+#[derive(Clone, Debug)]
+pub struct MouseLook {
+    /// Drehung in rad pro Pixel
+    pub sensitivity: f32,
+    /// Maus nach oben schaut nach unten
+    pub invert_y: bool,
+    captured: bool,
+    last: Option<(f32, f32)>,
+}
+
+impl MouseLook {
+    /// Umsehen mit `sensitivity` rad pro Pixel, Maus noch nicht gefangen
+    pub fn new(sensitivity: f32, invert_y: bool) -> Self {
+        MouseLook { sensitivity, invert_y, captured: false, last: None }
+    }
+
+    /// Ist die Maus gerade gefangen?
+    pub fn captured(&self) -> bool {
+        self.captured
+    }
+
+    /// Ein-/ausschalten; die erste Position danach zählt nicht als Bewegung
+    pub fn capture(&mut self, on: bool) {
+        self.captured = on;
+        self.last = None;
+    }
+
+    /// Mausbewegung seit dem letzten Aufruf in Pixeln, (0, 0) wenn nicht gefangen
+    pub fn delta(&mut self, pos: Option<(f32, f32)>) -> (f32, f32) {
+        if !self.captured {
+            return (0.0, 0.0);
+        }
+        let delta = match (self.last, pos) {
+            (Some((lx, ly)), Some((x, y))) => (x - lx, y - ly),
+            _ => (0.0, 0.0),
+        };
+        if pos.is_some() {
+            self.last = pos;
+        }
+        delta
+    }
+
+    /// Kamera um die Mausbewegung drehen. Maus nach unten = nach unten schauen.
+    pub fn apply(&self, camera: &mut Camera, (dx, dy): (f32, f32)) {
+        let dy = if self.invert_y { -dy } else { dy };
+        camera.turn(dx * self.sensitivity, dy * self.sensitivity);
+    }
+}
+
+impl Default for MouseLook {
+    fn default() -> Self {
+        MouseLook::new(0.003, false)
+    }
+}
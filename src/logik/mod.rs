@@ -0,0 +1,20 @@
+//! Spiellogik: Objekt-Welt, Kollision, Spieler, Eingabe und Szenen-Dateien.
+
+/// Tastenbelegung und Aktionen
+pub mod eingabe;
+/// Broad-Phase als Raster
+pub mod gitter;
+/// Hitboxen und Kollisionstests
+pub mod hitbox;
+/// Laufen, Springen und Fallen
+pub mod spieler;
+/// Strahlen gegen Hitboxen, Picking
+pub mod strahl;
+/// Szenen-Dateien (RON) laden
+pub mod szene;
+/// Umsehen mit der Maus
+pub mod maus;
+/// Objekte, Komponenten und Systeme
+pub mod welt;
+/// Fester Zeitschritt
+pub mod zeit;
@@ -0,0 +1,211 @@
+// logik/spieler.rs
+
+use crate::math::{Transform, Vec3};
+use crate::logik::hitbox::{check_aabb_collision, is_axis_aligned, resolve_movement, Collider, SweepHit, AABB, SKIN};
+use crate::logik::welt::{broadphase_system, Entity, World};
+
+/// Laufen, Fallen und Springen für ein Objekt mit Collider
+#[derive(Clone, Debug)]
+pub struct CharacterController {
+    /// Nur y wird genutzt (Fallen/Springen)
+    pub velocity: Vec3,
+    /// Steht gerade auf etwas
+    pub grounded: bool,
+    /// Einheiten pro s²
+    pub gravity: f32,
+    /// Einheiten pro s beim Absprung
+    pub jump_speed: f32,
+    /// So hohe Kanten werden hochgelaufen
+    pub step_height: f32,
+    /// Zurück hierher, wenn unter `kill_y` gefallen
+    pub spawn: Vec3,
+    /// Unterhalb davon gilt man als aus der Welt gefallen
+    pub kill_y: f32,
+}
+
+impl CharacterController {
+    /// Standardwerte, Start bei `spawn`
+    pub fn new(spawn: Vec3) -> Self {
+        CharacterController {
+            velocity: Vec3::ZERO,
+            grounded: false,
+            gravity: 20.0,
+            jump_speed: 7.0,
+            step_height: 0.35,
+            spawn,
+            kill_y: -100.0,
+        }
+    }
+
+    /// Springt nur vom Boden aus
+    pub fn jump(&mut self) {
+        if self.grounded {
+            self.velocity.y = self.jump_speed;
+            self.grounded = false;
+        }
+    }
+}
+
+/// Bewegt `entity` waagerecht um `walk` und lässt es fallen bzw. springen.
+/// An Wänden wird entlanggeglitten. Gibt alle Objekte zurück, die dabei
+/// berührt wurden (auch den Boden).
+pub fn character_system(world: &mut World, entity: Entity, walk: Vec3, dt: f32) -> Vec<Entity> {
+    broadphase_system(world);
+    let (Some(mut ctrl), Some(collider)) = (world.controllers.get(entity).cloned(), world.colliders.get(entity)) else {
+        return Vec::new();
+    };
+    // eigene Hülle relativ zur Position, dagegen laufen die Box-Sweeps
+    let own = world.transforms.get(entity).copied().unwrap_or_default();
+    let hitbox = collider.bounds(&Transform { position: Vec3::ZERO, ..own });
+    let start = world.position(entity);
+
+    // nur Hitboxen in Reichweite dieses Schritts
+    let reach = walk.length() + ctrl.step_height + (ctrl.velocity.y.abs() + ctrl.gravity * dt) * dt + SKIN;
+    let region = AABB { min: start + hitbox.min - Vec3::splat(reach), max: start + hitbox.max + Vec3::splat(reach) };
+    let mut others = Vec::new();
+    let mut boxes = Vec::new();
+    let mut shaped = Vec::new();
+    for e in world.broadphase.query(&region) {
+        let (Some(c), Some(t), Some(bounds)) = (world.colliders.get(e), world.transforms.get(e), world.broadphase.bounds(e)) else {
+            continue;
+        };
+        if e == entity {
+            continue;
+        }
+        // nur achsenparallele Boxen sind ihre eigene Hülle, alles andere
+        // (gedrehte Boxen, Kugeln, Kapseln, Dreiecke) wird genau geprüft
+        if !(matches!(c, Collider::Box(_)) && is_axis_aligned(t.rotation)) {
+            shaped.push(Shaped { entity: e, collider: c, transform: *t, bounds });
+        } else {
+            others.push(e);
+            boxes.push((bounds, Vec3::ZERO));
+        }
+    }
+    others.extend(shaped.iter().map(|s| s.entity));
+
+    let mover = Mover { collider, transform: own, hitbox, boxes: &boxes, shaped: &shaped };
+    let mut contacts = Vec::new();
+
+    // 🚶 waagerecht, an Wänden entlang
+    let walk = Vec3::new(walk.x, 0.0, walk.z);
+    let (mut pos, hits) = mover.resolve(start, walk);
+    if !hits.is_empty() && ctrl.grounded {
+        // kleine Kante: hoch, drüber, wieder runter – nehmen, wenn es weiter kommt
+        let up = Vec3::new(0.0, ctrl.step_height, 0.0);
+        let (raised, _) = mover.resolve(start, up);
+        let (over, _) = mover.resolve(raised, walk);
+        let (landed, below) = mover.resolve(over, -(raised - start));
+
+        let flat = |v: Vec3| Vec3::new(v.x, 0.0, v.z);
+        if !below.is_empty() && flat(landed - start).length() > flat(pos - start).length() + SKIN {
+            pos = landed;
+        }
+    }
+    contacts.extend(hits.iter().map(|&(i, _)| others[i]));
+
+    // ⬇️ senkrecht
+    ctrl.velocity.y -= ctrl.gravity * dt;
+    let (next, hits) = mover.resolve(pos, Vec3::new(0.0, ctrl.velocity.y * dt, 0.0));
+    pos = next;
+    ctrl.grounded = false;
+    for &(i, hit) in &hits {
+        if hit.normal.y > 0.0 {
+            ctrl.grounded = true;
+        }
+        contacts.push(others[i]);
+    }
+    if !hits.is_empty() {
+        // Boden oder Decke stoppt
+        ctrl.velocity.y = 0.0;
+    }
+
+    // 🕳️ aus der Welt gefallen
+    if pos.y < ctrl.kill_y {
+        pos = ctrl.spawn;
+        ctrl.velocity = Vec3::ZERO;
+    }
+
+    world.set_position(entity, pos);
+    world.controllers.insert(entity, ctrl);
+
+    contacts.sort_by_key(|e| e.0);
+    contacts.dedup();
+    contacts
+}
+
+// Hindernis, das nicht als achsenparallele Box gilt
+struct Shaped<'a> {
+    entity: Entity,
+    collider: &'a Collider,
+    transform: Transform,
+    bounds: AABB,
+}
+
+// Alles, was ein Bewegungsschritt braucht. Treffer-Indizes zählen erst
+// `boxes`, dann `shaped`.
+struct Mover<'a> {
+    collider: &'a Collider,
+    transform: Transform,
+    hitbox: AABB,
+    boxes: &'a [(AABB, Vec3)],
+    shaped: &'a [Shaped<'a>],
+}
+
+impl Mover<'_> {
+    // Wie `resolve_movement`: Achse für Achse gegen die Boxen sweepen, dann den
+    // Schritt so weit kürzen (Bisektion), bis die echten Formen frei sind
+    fn resolve(&self, start: Vec3, delta: Vec3) -> (Vec3, Vec<(usize, SweepHit)>) {
+        let mut pos = start;
+        let mut contacts = Vec::new();
+
+        let steps = [
+            Vec3::new(delta.x, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, delta.z),
+            Vec3::new(0.0, delta.y, 0.0),
+        ];
+        for step in steps {
+            let length = step.length();
+            if length == 0.0 {
+                continue;
+            }
+            let (mut target, mut hits) = resolve_movement(&self.hitbox, pos, step, self.boxes);
+
+            // nur Formen, deren Hülle im Weg liegt; wer schon drinsteckt, darf hinaus
+            let swept = AABB { min: self.hitbox.min + pos.min(target), max: self.hitbox.max + pos.max(target) };
+            let candidates: Vec<usize> = (0..self.shaped.len())
+                .filter(|&i| check_aabb_collision(&self.shaped[i].bounds, &swept, Vec3::ZERO, Vec3::ZERO) && !self.touches(pos, i))
+                .collect();
+            let blocking = |p: Vec3| candidates.iter().copied().filter(|&i| self.touches(p, i)).collect::<Vec<usize>>();
+
+            let mut touching = blocking(target);
+            if !touching.is_empty() {
+                let (mut lo, mut hi) = (0.0f32, 1.0f32);
+                for _ in 0..16 {
+                    let mid = (lo + hi) / 2.0;
+                    let b = blocking(pos.lerp(target, mid));
+                    if b.is_empty() {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                        touching = b;
+                    }
+                }
+                // die Form kommt vor jeder Box, deren Treffer gelten nicht mehr
+                let travelled = (target - pos).length() * lo;
+                target = pos.lerp(target, lo);
+                let hit = SweepHit { time: travelled / length, normal: step * (-1.0 / length) };
+                hits = touching.into_iter().map(|i| (self.boxes.len() + i, hit)).collect();
+            }
+
+            pos = target;
+            contacts.extend(hits);
+        }
+
+        (pos, contacts)
+    }
+
+    fn touches(&self, pos: Vec3, i: usize) -> bool {
+        let s = &self.shaped[i];
+        self.collider.intersects(&Transform { position: pos, ..self.transform }, s.collider, &s.transform)
+    }
+}
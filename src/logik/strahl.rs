@@ -0,0 +1,194 @@
+// logik/strahl.rs
+
+use crate::math::{Transform, Vec3};
+use crate::logik::hitbox::{Collider, AABB};
+use crate::logik::welt::{Entity, World};
+use crate::rander::kamera::Camera;
+
+/// Halbgerade ab `origin`, `dir` hat Länge 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    /// Startpunkt
+    pub origin: Vec3,
+    /// Richtung, Länge 1
+    pub dir: Vec3,
+}
+
+/// Was ein Strahl in der Welt getroffen hat
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// Getroffenes Objekt
+    pub entity: Entity,
+    /// Trefferpunkt in der Welt
+    pub point: Vec3,
+    /// Normale am Treffer, zeigt zum Strahl zurück
+    pub normal: Vec3,
+    /// Abstand vom Ursprung
+    pub distance: f32,
+}
+
+impl Ray {
+    /// `dir` wird normiert
+    pub fn new(origin: Vec3, dir: Vec3) -> Self {
+        Ray { origin, dir: dir.normalize() }
+    }
+
+    /// Punkt im Abstand `distance`
+    pub fn at(&self, distance: f32) -> Vec3 {
+        self.origin + self.dir * distance
+    }
+
+    /// Strahl durch den Pixel (`x`, `y`), Gegenstück zu `Camera::to_screen`.
+    /// Mitte eines Pixels ist `x + 0.5`.
+    pub fn from_screen(camera: &Camera, x: f32, y: f32, width: usize, height: usize) -> Ray {
+        let ndc_x = x / width as f32 * 2.0 - 1.0;
+        let ndc_y = 1.0 - y / height as f32 * 2.0;
+
+        let Some(inv) = (camera.projection() * camera.view()).inverse() else {
+            return Ray::new(camera.position, camera.forward());
+        };
+        let point = |depth: f32| {
+            let [x, y, z, w] = inv.transform_vec4([ndc_x, ndc_y, depth, 1.0]);
+            Vec3::new(x, y, z) / w
+        };
+        Ray::new(camera.position, point(1.0) - point(0.0))
+    }
+}
+
+/// Strahl gegen Box (Slab-Test): Abstand und Normale beim Eintritt.
+/// Startet der Strahl in der Box, gibt es keinen Treffer.
+pub fn ray_aabb(ray: &Ray, aabb: &AABB) -> Option<(f32, Vec3)> {
+    let axes = [
+        (ray.origin.x, ray.dir.x, aabb.min.x, aabb.max.x, Vec3::new(1.0, 0.0, 0.0)),
+        (ray.origin.y, ray.dir.y, aabb.min.y, aabb.max.y, Vec3::UP),
+        (ray.origin.z, ray.dir.z, aabb.min.z, aabb.max.z, Vec3::new(0.0, 0.0, 1.0)),
+    ];
+
+    let mut near = f32::MIN;
+    let mut far = f32::MAX;
+    let mut normal = Vec3::ZERO;
+    for (o, d, min, max, axis) in axes {
+        if d == 0.0 {
+            // parallel: nur wenn schon zwischen den Ebenen
+            if o < min || o > max {
+                return None;
+            }
+            continue;
+        }
+        let (t0, t1) = ((min - o) / d, (max - o) / d);
+        let (t0, t1) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+        if t0 > near {
+            near = t0;
+            normal = if d > 0.0 { -axis } else { axis };
+        }
+        far = far.min(t1);
+    }
+
+    (near <= far && near >= 0.0).then_some((near, normal))
+}
+
+/// Strahl gegen Dreieck (Möller–Trumbore), beide Seiten zählen
+pub fn ray_triangle(ray: &Ray, [a, b, c]: &[Vec3; 3]) -> Option<(f32, Vec3)> {
+    let (e1, e2) = (*b - *a, *c - *a);
+    let p = ray.dir.cross(e2);
+    let det = e1.dot(p);
+    if det.abs() < 1e-9 {
+        return None;
+    }
+
+    let s = ray.origin - *a;
+    let u = s.dot(p) / det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = s.cross(e1);
+    let v = ray.dir.dot(q) / det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = e2.dot(q) / det;
+    if t < 0.0 {
+        return None;
+    }
+
+    let n = e1.cross(e2).normalize();
+    Some((t, if n.dot(ray.dir) > 0.0 { -n } else { n }))
+}
+
+/// Strahl gegen Kugel, nur von außen
+pub fn ray_sphere(ray: &Ray, center: Vec3, radius: f32) -> Option<(f32, Vec3)> {
+    let oc = ray.origin - center;
+    let b = oc.dot(ray.dir);
+    let c = oc.length_squared() - radius * radius;
+    let disc = b * b - c;
+    if c < 0.0 || disc < 0.0 {
+        return None;
+    }
+    let t = -b - disc.sqrt();
+    (t >= 0.0).then(|| (t, (ray.at(t) - center) / radius))
+}
+
+// Strahl gegen Kapsel: Mantel des Zylinders a–b, sonst die Halbkugeln
+fn ray_capsule(ray: &Ray, a: Vec3, b: Vec3, radius: f32) -> Option<(f32, Vec3)> {
+    let caps = [a, b].into_iter().filter_map(|c| ray_sphere(ray, c, radius));
+
+    let axis = b - a;
+    let len = axis.length();
+    let body = (len > 0.0).then(|| {
+        let m = axis / len;
+        let perp = |v: Vec3| v - m * v.dot(m);
+        let (d, o) = (perp(ray.dir), perp(ray.origin - a));
+        let (qa, qb, qc) = (d.length_squared(), 2.0 * d.dot(o), o.length_squared() - radius * radius);
+        let disc = qb * qb - 4.0 * qa * qc;
+        if qa == 0.0 || qc < 0.0 || disc < 0.0 {
+            return None;
+        }
+        let t = (-qb - disc.sqrt()) / (2.0 * qa);
+        let s = (ray.at(t) - a).dot(m);
+        (t >= 0.0 && (0.0..=len).contains(&s)).then(|| (t, (ray.at(t) - (a + m * s)) / radius))
+    });
+
+    caps.chain(body.flatten()).min_by(|x, y| x.0.total_cmp(&y.0))
+}
+
+/// Strahl gegen eine Hitbox mit dem Transform ihres Objekts
+pub fn ray_collider(ray: &Ray, collider: &Collider, t: &Transform) -> Option<(f32, Vec3)> {
+    let m = t.matrix();
+    let s = t.scale.abs();
+    let scale = s.x.max(s.y).max(s.z);
+    match collider {
+        Collider::Box(aabb) => {
+            // in Objektkoordinaten; `dir` bleibt ungenormt, damit der Abstand passt
+            let inv = m.inverse()?;
+            let local = Ray { origin: inv.transform_point(ray.origin), dir: inv.transform_vector(ray.dir) };
+            ray_aabb(&local, aabb).map(|(dist, n)| (dist, t.transform_normal(n)))
+        }
+        Collider::Sphere { center, radius } => ray_sphere(ray, m.transform_point(*center), radius * scale),
+        Collider::Capsule { a, b, radius } => ray_capsule(ray, m.transform_point(*a), m.transform_point(*b), radius * scale),
+        Collider::Mesh(tris) => tris
+            .iter()
+            .filter_map(|tri| ray_triangle(ray, &tri.map(|p| m.transform_point(p))))
+            .min_by(|x, y| x.0.total_cmp(&y.0)),
+    }
+}
+
+/// Nächstes Objekt mit Collider entlang des Strahls, bis `max_distance`.
+/// Objekte, in denen der Strahl startet (z.B. der Spieler), werden übersprungen.
+/// Kandidaten kommen aus der Broad-Phase.
+pub fn raycast(world: &World, ray: &Ray, max_distance: f32) -> Option<RayHit> {
+    world
+        .broadphase
+        .query_ray(ray.origin, ray.dir, max_distance)
+        .into_iter()
+        .filter_map(|e| {
+            let (distance, normal) = ray_collider(ray, world.colliders.get(e)?, world.transforms.get(e)?)?;
+            Some(RayHit { entity: e, point: ray.at(distance), normal, distance })
+        })
+        .filter(|hit| hit.distance <= max_distance)
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+}
+
+/// Was liegt unter dem Pixel (`x`, `y`)? Z.B. die Bildmitte fürs Fadenkreuz.
+pub fn pick(world: &World, camera: &Camera, x: f32, y: f32, width: usize, height: usize) -> Option<RayHit> {
+    raycast(world, &Ray::from_screen(camera, x, y, width, height), camera.far)
+}
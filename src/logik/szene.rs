@@ -0,0 +1,275 @@
+// logik/szene.rs
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use crate::math::{Quat, Transform, Vec3};
+use crate::logik::hitbox::{load_obj_hitbox, Collider, AABB};
+use crate::rander::fehler::LoadError;
+use crate::logik::spieler::CharacterController;
+use crate::logik::welt::{broadphase_system, MeshRenderer, ParticleEmitter, World};
+use crate::rander::rander_model::*;
+
+// 📄 Beschreibung, wie sie in der .ron-Datei steht
+
+/// Inhalt einer Szenen-Datei. Pfade sind relativ zur Datei.
+#[derive(Debug, Deserialize)]
+pub struct SceneDesc {
+    /// Richtung zum Licht
+    pub light_dir: [f32; 3],
+    /// Start und Hitbox des Spielers
+    pub player: PlayerDesc,
+    /// Alle übrigen Objekte
+    #[serde(default)]
+    pub entities: Vec<EntityDesc>,
+}
+
+/// Der Spieler; ohne Hitbox fliegt er frei
+#[derive(Debug, Deserialize)]
+pub struct PlayerDesc {
+    /// Startpunkt, auch Respawn
+    pub position: [f32; 3],
+    /// Mit Hitbox gibt es Schwerkraft und Springen
+    #[serde(default)]
+    pub hitbox: Option<HitboxDesc>,
+    /// Partikel-Quelle am Spieler
+    #[serde(default)]
+    pub emitter: Option<EmitterDesc>,
+}
+
+/// Ein Objekt mit Mesh
+#[derive(Debug, Deserialize)]
+pub struct EntityDesc {
+    /// Zum Wiederfinden mit `World::find`
+    pub name: String,
+    /// OBJ-Datei
+    pub mesh: String,
+    /// Zusätzliche MTL-Datei, sonst nur die `mtllib` der OBJ
+    #[serde(default)]
+    pub material: Option<String>,
+    /// Lage, ohne Angabe im Ursprung
+    #[serde(default)]
+    pub transform: TransformDesc,
+    /// Farbe für Faces ohne `usemtl`
+    #[serde(default = "default_color")]
+    pub color: [u8; 3],
+    /// Welche Seite weggelassen wird
+    #[serde(default)]
+    pub cull: CullMode,
+    /// Licht pro Face, Eckpunkt oder Pixel
+    #[serde(default)]
+    pub shading: ShadingMode,
+    /// Ohne Hitbox kann man hindurchlaufen
+    #[serde(default)]
+    pub hitbox: Option<HitboxDesc>,
+    /// Partikel-Quelle am Objekt
+    #[serde(default)]
+    pub emitter: Option<EmitterDesc>,
+}
+
+fn default_color() -> [u8; 3] {
+    [255, 255, 255]
+}
+
+/// Lage eines Objekts, fehlende Felder bleiben neutral
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct TransformDesc {
+    /// Verschiebung
+    pub position: [f32; 3],
+    /// Pitch, yaw, roll in Grad
+    pub rotation: [f32; 3],
+    /// Größe des Objekts pro Achse
+    pub scale: [f32; 3],
+}
+
+impl Default for TransformDesc {
+    fn default() -> Self {
+        TransformDesc {
+            position: [0.0; 3],
+            rotation: [0.0; 3],
+            scale: [1.0; 3],
+        }
+    }
+}
+
+/// Woher die Hitbox kommt
+#[derive(Debug, Clone, Deserialize)]
+pub enum HitboxDesc {
+    /// Box um das Mesh des Objekts
+    Mesh,
+    /// Genau die Dreiecke des Meshes
+    Triangles,
+    /// Box um eine eigene OBJ
+    Obj(String),
+    /// Box in Objektkoordinaten
+    Box {
+        /// Kleinste Ecke
+        min: [f32; 3],
+        /// Größte Ecke
+        max: [f32; 3],
+    },
+    /// Kugel in Objektkoordinaten
+    Sphere {
+        /// Mittelpunkt
+        center: [f32; 3],
+        /// Radius
+        radius: f32,
+    },
+    /// Kapsel um die Strecke `a`–`b`
+    Capsule {
+        /// Ein Ende
+        a: [f32; 3],
+        /// Anderes Ende
+        b: [f32; 3],
+        /// Radius
+        radius: f32,
+    },
+}
+
+/// Wann ein Emitter neue Partikel erzeugt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum EmitterTrigger {
+    /// Jedes Frame
+    Always,
+    /// Wenn der Spieler das Objekt berührt
+    Collision,
+    /// Feuer-Taste, nur beim Spieler
+    Fire,
+}
+
+/// Partikel-Quelle
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmitterDesc {
+    /// Wann Partikel entstehen
+    pub trigger: EmitterTrigger,
+    /// Partikel pro Auslösung
+    pub count: usize,
+    /// Anfangs-Lebensdauer
+    pub life: f32,
+    /// Abstand zum Ursprung des Objekts
+    #[serde(default)]
+    pub offset: [f32; 3],
+    /// Je größer, desto langsamer (pro Achse)
+    pub slowness: [f32; 3],
+    /// Farbe
+    pub color: [u8; 3],
+}
+
+fn vec3(v: [f32; 3]) -> Vec3 {
+    Vec3::new(v[0], v[1], v[2])
+}
+
+/// Lädt eine Szene aus einer RON-Datei in eine neue `World`.
+///
+/// Nur eine kaputte Szenen-Datei ist ein Fehler. Fehlende oder kaputte Meshes,
+/// Materialien und Hitboxen werden ersetzt (Ersatz-Würfel, keine Hitbox) und
+/// als zweiter Wert zurückgegeben, damit das Spiel trotzdem startet.
+pub fn load_scene(path: &str) -> Result<(World, Vec<LoadError>), LoadError> {
+    let text = fs::read_to_string(path).map_err(|e| LoadError::new(path, None, format!("Konnte Szene nicht öffnen: {}", e)))?;
+    let desc: SceneDesc = ron::from_str(&text)
+        .map_err(|e| LoadError::at(path, e.position.line, e.code.to_string()))?;
+
+    let dir = Path::new(path).parent().unwrap_or(Path::new(""));
+    let resolve = |p: &str| dir.join(p).to_string_lossy().to_string();
+
+    let mut warnings = Vec::new();
+    let mut world = World::new(vec3(desc.light_dir));
+
+    for e in desc.entities {
+        let mesh_path = resolve(&e.mesh);
+        let (mut model, loaded) = match load_obj(&mesh_path) {
+            Ok(model) => (model, true),
+            Err(err) => {
+                warnings.push(err);
+                (placeholder_model(), false)
+            }
+        };
+        model.cull_mode = e.cull;
+        model.shading = e.shading;
+
+        let mut materials = load_model_materials(&model).unwrap_or_else(|err| {
+            warnings.push(err);
+            HashMap::new()
+        });
+        if let Some(mtl) = &e.material {
+            match load_mtl(&resolve(mtl)) {
+                Ok(extra) => materials.extend(extra),
+                Err(err) => warnings.push(err),
+            }
+        }
+        // Ersatz-Würfel bleibt in der Fehlerfarbe
+        if loaded {
+            let [r, g, b] = e.color;
+            materials.entry(String::new()).or_insert_with(|| Material {
+                diffuse: Vec3::new(r as f32, g as f32, b as f32) / 255.0,
+                ..Material::default()
+            });
+        }
+
+        let hitbox = match &e.hitbox {
+            Some(HitboxDesc::Mesh) if loaded => model_hitbox(&model),
+            Some(HitboxDesc::Triangles) if loaded => Collider::from_model(&model),
+            Some(HitboxDesc::Mesh | HitboxDesc::Triangles) => None,
+            Some(desc) => load_hitbox(desc, &resolve, &mut warnings),
+            None => None,
+        };
+
+        let t = &e.transform;
+        let [pitch, yaw, roll] = t.rotation;
+        let transform = Transform {
+            position: vec3(t.position),
+            rotation: Quat::from_euler(pitch.to_radians(), yaw.to_radians(), roll.to_radians()),
+            scale: vec3(t.scale),
+        };
+
+        let entity = world.spawn(&e.name);
+        world.transforms.insert(entity, transform);
+        world.meshes.insert(entity, MeshRenderer { model, materials });
+        if let Some(hitbox) = hitbox {
+            world.colliders.insert(entity, hitbox);
+        }
+        if let Some(emitter) = e.emitter {
+            world.emitters.insert(entity, ParticleEmitter::new(emitter));
+        }
+    }
+
+    let player = world.player;
+    world.set_position(player, vec3(desc.player.position));
+    let hitbox = match &desc.player.hitbox {
+        Some(HitboxDesc::Mesh | HitboxDesc::Triangles) => {
+            warnings.push(LoadError::new(path, None, "Spieler hat kein Mesh für 'Mesh'-Hitbox"));
+            None
+        }
+        Some(desc) => load_hitbox(desc, &resolve, &mut warnings),
+        None => None,
+    };
+    // Mit Hitbox läuft der Spieler mit Schwerkraft, sonst fliegt er frei
+    if let Some(hitbox) = hitbox {
+        world.colliders.insert(player, hitbox);
+        world.controllers.insert(player, CharacterController::new(vec3(desc.player.position)));
+    }
+    if let Some(emitter) = desc.player.emitter {
+        world.emitters.insert(player, ParticleEmitter::new(emitter));
+    }
+    broadphase_system(&mut world);
+
+    Ok((world, warnings))
+}
+
+fn model_hitbox(model: &Model) -> Option<Collider> {
+    let first = *model.vertices.first()?;
+    let (min, max) = model.vertices.iter().fold((first, first), |(min, max), v| (min.min(*v), max.max(*v)));
+    Some(Collider::Box(AABB { min, max }))
+}
+
+fn load_hitbox(desc: &HitboxDesc, resolve: &impl Fn(&str) -> String, warnings: &mut Vec<LoadError>) -> Option<Collider> {
+    match desc {
+        HitboxDesc::Mesh | HitboxDesc::Triangles => None,
+        HitboxDesc::Obj(p) => load_obj_hitbox(&resolve(p)).map(Collider::Box).map_err(|e| warnings.push(e)).ok(),
+        HitboxDesc::Box { min, max } => Some(Collider::Box(AABB { min: vec3(*min), max: vec3(*max) })),
+        HitboxDesc::Sphere { center, radius } => Some(Collider::Sphere { center: vec3(*center), radius: *radius }),
+        HitboxDesc::Capsule { a, b, radius } => Some(Collider::Capsule { a: vec3(*a), b: vec3(*b), radius: *radius }),
+    }
+}
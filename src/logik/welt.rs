@@ -0,0 +1,310 @@
+// logik/welt.rs
+
+use std::collections::HashMap;
+use crate::math::{Transform, Vec3};
+use crate::logik::gitter::SpatialHash;
+use crate::logik::hitbox::Collider;
+use crate::logik::spieler::CharacterController;
+use crate::logik::szene::{EmitterDesc, EmitterTrigger};
+use crate::rander::kamera::Camera;
+use crate::rander::offscreen::{ParticleCloud, Scene, SceneObject};
+use crate::rander::partikel::{partikel_lode, update_partikel, Particle};
+use crate::rander::rander_model::{Material, Model};
+
+// Kantenlänge der Zellen in der Broad-Phase
+const BROADPHASE_CELL: f32 = 4.0;
+
+/// Id eines Objekts in der `World`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Entity(pub usize);
+
+/// Alle Komponenten eines Typs, Index = Entity
+pub struct Storage<T> {
+    items: Vec<Option<T>>,
+}
+
+impl<T> Default for Storage<T> {
+    fn default() -> Self {
+        Storage { items: Vec::new() }
+    }
+}
+
+impl<T> Storage<T> {
+    /// Komponente setzen, eine alte wird ersetzt
+    pub fn insert(&mut self, entity: Entity, component: T) {
+        if entity.0 >= self.items.len() {
+            self.items.resize_with(entity.0 + 1, || None);
+        }
+        self.items[entity.0] = Some(component);
+    }
+
+    /// Komponente herausnehmen
+    pub fn remove(&mut self, entity: Entity) -> Option<T> {
+        self.items.get_mut(entity.0).and_then(Option::take)
+    }
+
+    /// Komponente von `entity`, falls vorhanden
+    pub fn get(&self, entity: Entity) -> Option<&T> {
+        self.items.get(entity.0).and_then(Option::as_ref)
+    }
+
+    /// Wie `get`, zum Ändern
+    pub fn get_mut(&mut self, entity: Entity) -> Option<&mut T> {
+        self.items.get_mut(entity.0).and_then(Option::as_mut)
+    }
+
+    /// Alle vorhandenen Komponenten, nach Id
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &T)> {
+        self.items.iter().enumerate().filter_map(|(i, c)| Some((Entity(i), c.as_ref()?)))
+    }
+
+    /// Wie `iter`, zum Ändern
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Entity, &mut T)> {
+        self.items.iter_mut().enumerate().filter_map(|(i, c)| Some((Entity(i), c.as_mut()?)))
+    }
+}
+
+// 🧩 Komponenten
+
+/// Mesh und Materialien zum Zeichnen
+pub struct MeshRenderer {
+    /// Geladenes Mesh
+    pub model: Model,
+    /// Materialien nach Name
+    pub materials: HashMap<String, Material>,
+}
+
+/// Partikel-Quelle mit ihren lebenden Partikeln
+pub struct ParticleEmitter {
+    /// Einstellungen aus der Szene
+    pub desc: EmitterDesc,
+    /// Lebende Partikel
+    pub particles: Vec<Particle>,
+}
+
+impl ParticleEmitter {
+    /// Noch ohne Partikel
+    pub fn new(desc: EmitterDesc) -> Self {
+        ParticleEmitter { desc, particles: Vec::new() }
+    }
+
+    /// `count` neue Partikel bei `origin` (+ offset)
+    pub fn emit(&mut self, origin: Vec3) {
+        let [x, y, z] = self.desc.offset;
+        let pos = origin + Vec3::new(x, y, z);
+        self.particles.extend(partikel_lode(self.desc.count, self.desc.life).into_iter().map(|mut p| {
+            p.pos = pos;
+            p
+        }));
+    }
+
+    /// Partikel um `dt` Sekunden weiterbewegen
+    pub fn update(&mut self, camera: &Camera, dt: f32) {
+        let s = self.desc.slowness;
+        update_partikel(&mut self.particles, (s[0], s[1], s[2]), camera, dt);
+    }
+}
+
+// 🌍 Welt
+
+/// Alle Objekte mit ihren Komponenten. Ein Objekt ist nur eine Id,
+/// was es kann, hängt davon ab, in welchen `Storage`s es steht.
+pub struct World {
+    next: usize,
+    /// Name jedes Objekts
+    pub names: Storage<String>,
+    /// Lage in der Welt
+    pub transforms: Storage<Transform>,
+    /// Was gezeichnet wird
+    pub meshes: Storage<MeshRenderer>,
+    /// Hitboxen
+    pub colliders: Storage<Collider>,
+    /// Partikel-Quellen
+    pub emitters: Storage<ParticleEmitter>,
+    /// Laufen und Springen
+    pub controllers: Storage<CharacterController>,
+    /// Hüllen aller Collider, aktuell nach `broadphase_system`
+    pub broadphase: SpatialHash,
+    indexed: HashMap<Entity, Transform>, // Transform beim Einsortieren
+    /// Der Spieler, gibt es immer
+    pub player: Entity,
+    /// Richtung zum Licht
+    pub light_dir: Vec3,
+}
+
+impl World {
+    /// Leere Welt, nur mit dem Spieler
+    pub fn new(light_dir: Vec3) -> Self {
+        let mut world = World {
+            next: 0,
+            names: Storage::default(),
+            transforms: Storage::default(),
+            meshes: Storage::default(),
+            colliders: Storage::default(),
+            emitters: Storage::default(),
+            controllers: Storage::default(),
+            broadphase: SpatialHash::new(BROADPHASE_CELL),
+            indexed: HashMap::new(),
+            player: Entity(0),
+            light_dir,
+        };
+        world.player = world.spawn("player");
+        world.transforms.insert(world.player, Transform::default());
+        world
+    }
+
+    /// Neues Objekt, zunächst nur mit Namen
+    pub fn spawn(&mut self, name: &str) -> Entity {
+        let entity = Entity(self.next);
+        self.next += 1;
+        self.names.insert(entity, name.to_string());
+        entity
+    }
+
+    /// Entfernt alle Komponenten; die Id wird nicht wiederverwendet
+    pub fn despawn(&mut self, entity: Entity) {
+        self.names.remove(entity);
+        self.transforms.remove(entity);
+        self.meshes.remove(entity);
+        self.colliders.remove(entity);
+        self.emitters.remove(entity);
+        self.controllers.remove(entity);
+        self.broadphase.remove(entity);
+        self.indexed.remove(&entity);
+    }
+
+    /// Erstes Objekt mit diesem Namen
+    pub fn find(&self, name: &str) -> Option<Entity> {
+        self.names.iter().find(|(_, n)| *n == name).map(|(e, _)| e)
+    }
+
+    /// Position, ohne Transform der Ursprung
+    pub fn position(&self, entity: Entity) -> Vec3 {
+        self.transforms.get(entity).map_or(Vec3::ZERO, |t| t.position)
+    }
+
+    /// Nur wenn es einen Transform hat
+    pub fn set_position(&mut self, entity: Entity, position: Vec3) {
+        if let Some(t) = self.transforms.get_mut(entity) {
+            t.position = position;
+        }
+    }
+}
+
+// ⚙️ Systeme
+
+/// Alles mit Transform und Mesh plus alle Partikel als Szene für `render_scene`
+pub fn render_system(world: &World, strake: f32) -> Scene<'_> {
+    let objects = world
+        .meshes
+        .iter()
+        .filter_map(|(e, mesh)| {
+            Some(SceneObject {
+                model: &mesh.model,
+                transform: *world.transforms.get(e)?,
+                materials: &mesh.materials,
+            })
+        })
+        .collect();
+
+    let particles = world
+        .emitters
+        .iter()
+        .map(|(_, emitter)| {
+            let [r, g, b] = emitter.desc.color;
+            ParticleCloud { particles: &emitter.particles, color: [r as f32, g as f32, b as f32] }
+        })
+        .collect();
+
+    Scene { objects, particles, light_dir: world.light_dir, strake, overlay: None }
+}
+
+/// Broad-Phase auf den Stand bringen: neue, bewegte und entfernte Collider.
+/// Nur wer seinen Transform geändert hat, wird neu einsortiert; wer die Form
+/// eines Colliders ändert, nimmt ihn vorher mit `broadphase.remove` heraus.
+pub fn broadphase_system(world: &mut World) {
+    let gone: Vec<Entity> = world
+        .indexed
+        .keys()
+        .filter(|e| world.colliders.get(**e).is_none() || world.transforms.get(**e).is_none())
+        .copied()
+        .collect();
+    for e in gone {
+        world.broadphase.remove(e);
+        world.indexed.remove(&e);
+    }
+
+    for (e, collider) in world.colliders.iter() {
+        let Some(t) = world.transforms.get(e) else {
+            continue;
+        };
+        if world.indexed.get(&e) == Some(t) && world.broadphase.bounds(e).is_some() {
+            continue;
+        }
+        world.broadphase.insert(e, collider.bounds(t));
+        world.indexed.insert(e, *t);
+    }
+}
+
+/// Alle Objekte, deren Hitbox die von `entity` berührt
+pub fn collision_system(world: &World, entity: Entity) -> Vec<Entity> {
+    collisions_at(world, entity, world.position(entity))
+}
+
+/// Wie `collision_system`, aber mit `entity` an `pos` statt an seiner Position.
+/// Andere Objekte kommen aus der Broad-Phase.
+pub fn collisions_at(world: &World, entity: Entity, pos: Vec3) -> Vec<Entity> {
+    let Some(collider) = world.colliders.get(entity) else {
+        return Vec::new();
+    };
+    let mut transform = world.transforms.get(entity).copied().unwrap_or_default();
+    transform.position = pos;
+
+    world
+        .broadphase
+        .query(&collider.bounds(&transform))
+        .into_iter()
+        .filter(|other| {
+            *other != entity
+                && match (world.colliders.get(*other), world.transforms.get(*other)) {
+                    (Some(c), Some(t)) => collider.intersects(&transform, c, t),
+                    _ => false,
+                }
+        })
+        .collect()
+}
+
+/// Alle Paare von Objekten, die sich gerade berühren
+pub fn collision_pairs(world: &World) -> Vec<(Entity, Entity)> {
+    world
+        .broadphase
+        .pairs()
+        .into_iter()
+        .filter(|(a, b)| {
+            match (world.colliders.get(*a), world.transforms.get(*a), world.colliders.get(*b), world.transforms.get(*b)) {
+                (Some(ca), Some(ta), Some(cb), Some(tb)) => ca.intersects(ta, cb, tb),
+                _ => false,
+            }
+        })
+        .collect()
+}
+
+/// Emitter mit passendem `trigger` Partikel erzeugen lassen,
+/// bei `Some(entities)` nur die von diesen Objekten
+pub fn emit_system(world: &mut World, trigger: EmitterTrigger, only: Option<&[Entity]>) {
+    for (e, emitter) in world.emitters.iter_mut() {
+        if emitter.desc.trigger != trigger || only.is_some_and(|o| !o.contains(&e)) {
+            continue;
+        }
+        if let Some(t) = world.transforms.get(e) {
+            emitter.emit(t.position);
+        }
+    }
+}
+
+/// Alle Partikel um `dt` Sekunden weiterbewegen, `camera` bestimmt, was sichtbar ist
+pub fn particle_system(world: &mut World, camera: &Camera, dt: f32) {
+    for (_, emitter) in world.emitters.iter_mut() {
+        emitter.update(camera, dt);
+    }
+}
@@ -0,0 +1,54 @@
+// logik/zeit.rs
+
+use std::time::Instant;
+
+/// Feste Simulationsschritte unabhängig von der Bildrate.
+///
+/// Pro Bild `advance()` aufrufen, dann so viele Schritte à `dt` simulieren,
+/// wie zurückgegeben werden, und mit `alpha()` zwischen dem vorletzten und
+/// letzten Zustand interpolieren.
+pub struct FixedStep {
+    /// Länge eines Schritts in s
+    pub dt: f32,
+    /// Längere Bilder (z.B. nach einem Hänger) werden gekappt
+    pub max_frame: f32,
+    accumulator: f32,
+    last: Instant,
+}
+
+impl FixedStep {
+    /// `hz` Simulationsschritte pro Sekunde
+    pub fn new(hz: f32) -> Self {
+        FixedStep {
+            dt: 1.0 / hz,
+            max_frame: 0.25,
+            accumulator: 0.0,
+            last: Instant::now(),
+        }
+    }
+
+    /// Vergangene Zeit seit dem letzten Aufruf einrechnen
+    pub fn advance(&mut self) -> u32 {
+        let now = Instant::now();
+        let frame = (now - self.last).as_secs_f32();
+        self.last = now;
+        self.advance_by(frame)
+    }
+
+    /// Wie `advance`, aber mit vorgegebener Bildzeit in Sekunden
+    pub fn advance_by(&mut self, frame: f32) -> u32 {
+        self.accumulator += frame.min(self.max_frame);
+
+        let mut steps = 0;
+        while self.accumulator >= self.dt {
+            self.accumulator -= self.dt;
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Anteil des nächsten Schritts, der schon vergangen ist (0..1)
+    pub fn alpha(&self) -> f32 {
+        self.accumulator / self.dt
+    }
+}
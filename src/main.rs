@@ -1,44 +1,74 @@
-use minifb::{Key, Window, WindowOptions};
-use rand::{Rng, thread_rng};
+use minifb::{MouseButton, MouseMode, Window, WindowOptions};
 use std::f32::consts::PI;
 use std::time::Instant;
 
-mod rander;
-mod logik;
-
-use rander::rander_model::*;
-use rander::partikel::*;
-use rander::fps::*;
-use logik::hitbox::*;
+use rander3d::rander::kamera::Camera;
+use rander3d::rander::framebuffer::Framebuffer;
+use rander3d::rander::offscreen::*;
+use rander3d::logik::welt::*;
+use rander3d::logik::zeit::FixedStep;
+use rander3d::logik::maus::MouseLook;
+use rander3d::logik::eingabe::*;
+use rander3d::logik::spieler::character_system;
+use rander3d::logik::szene::*;
+use rander3d::math::Vec3;
 
 const WIDTH: usize = 640;
 const HEIGHT: usize = 360;
 
+// Simulationsschritte pro Sekunde, unabhängig von den FPS
+const SIM_HZ: f32 = 60.0;
+// Geschwindigkeiten pro Sekunde
+const MOVE_SPEED: f32 = 6.0;
+const TURN_SPEED: f32 = 6.0; // rad
+const ZOOM_SPEED: f32 = 60.0; // Grad Sichtfeld
+const DAY_SPEED: f32 = 0.03; // starke pro Sekunde
+
 fn main() {
-    let mut buffer: Vec<u32> = vec![0; WIDTH * HEIGHT];
-    let mut zbuffer: Vec<f32> = vec![f32::MAX; WIDTH * HEIGHT];
-    let mut world_pos = (0.0 as f32, -0.5 as f32, -5.0 as f32, 0.0 as f32, 0.0 as f32, 0.0 as f32);
-    let mut window = Window::new(
-        "game_for_idk",
-        WIDTH,
-        HEIGHT,
-        WindowOptions::default(),
-    )
-    .unwrap_or_else(|e| {
-        panic!("Fehler beim Öffnen des Fensters: {}", e);
-    });
-    let mut rng = thread_rng();
-    // 💡 Lichtquelle – global oder konfigurierbar
-    let light_dir = Vec3 { x: 0.1, y: 1.0, z: -0.1};
-    let mut starke = (0.0, false);
-    let mut t = starke.0;
-    let mut light_color = (
-        0.9 * t + 0.1, // R
-        0.85 * t + 0.15, // G
-        0.6 + (1.0 - t) * 0.4, // B – mehr Blau bei Nacht
-    );
+    let mut fb = Framebuffer::new(WIDTH, HEIGHT);
+    let args: Vec<String> = std::env::args().collect();
+    // Wert hinter dem Schalter; ein folgender Schalter ist kein Wert
+    let arg = |name: &str| {
+        args.iter()
+            .position(|a| a == name)
+            .map(|i| args.get(i + 1).map(String::as_str).filter(|v| !v.starts_with("--")))
+    };
+
+    // "--scene datei.ron": andere Szene laden
+    let scene_path = arg("--scene").flatten().unwrap_or("assets/scene.ron");
+    let mut world = match load_scene(scene_path) {
+        Ok((world, warnings)) => {
+            // Kaputte Assets melden, das Spiel läuft mit Ersatz weiter
+            for w in warnings {
+                eprintln!("{}", w);
+            }
+            world
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
 
+    // "--input datei.ron": andere Tastenbelegung
+    let input_path = arg("--input").flatten().unwrap_or("assets/input.ron");
+    let mut input = Input::new(load_input_config(input_path).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        InputConfig::default()
+    }));
 
+    // "--mouse-sensitivity 0.003" (rad pro Pixel) und "--invert-mouse" gehen vor der Datei
+    let mut mouse = MouseLook::new(input.config.mouse_sensitivity, input.config.invert_mouse);
+    if let Some(s) = arg("--mouse-sensitivity").flatten().and_then(|s| s.parse().ok()) {
+        mouse.sensitivity = s;
+    }
+    if args.iter().any(|a| a == "--invert-mouse") {
+        mouse.invert_y = true;
+    }
+
+    let mut camera = Camera::new(60.0, WIDTH as f32 / HEIGHT as f32, 0.1, 1000.0);
+    camera.position = world.position(world.player);
+    let mut starke = (0.0, false);
 
     // for fps
     let mut fps_counter = 0;
@@ -46,208 +76,128 @@ fn main() {
     let mut last_fps_time = Instant::now();
     let mut fps_text: String;
 
-    let cube = (
-        load_obj("assets/cube.obj"),
-        (0.0, 0.0, 0.0), // der pos von cube
-        15.0,
-        rgb(100, 170, 255),
-        load_obj_hitbox("assets/cube.obj"),
-        load_mtl("assets/cube.mtl"),
-    );
-
-    let plate = (
-        load_obj("assets/plate.obj"),
-        (5.0, 0.0, 5.0), // der pos von cube
-        1.0, 
-        rgb(87, 87, 87),
-        load_obj_hitbox("assets/plate.obj"),
-        load_mtl("assets/plate.mtl"),
-    );
-
-    let trasch = (
-        load_obj("assets/trasch.obj"),
-        (15.0, -1.0, 15.0), // der pos von cube
-        15.0, 
-        rgb(87, 87, 87),
-        load_obj_hitbox("assets/trasch.obj"),
-        load_mtl("assets/trasch.mtl"),
-    );
-
-    let player = load_obj_hitbox("assets/player.obj");
-
-    // Bewegungsgeschwindigkeit
-    let speed = 0.1;
+    // "--headless [bild.png]": das erste Bild ohne Fenster rendern und beenden,
+    // gleich wie im Fenster (mit den Always-Partikeln und "FPS: 0")
+    if let Some(path) = arg("--headless") {
+        let path = path.unwrap_or("frame.png");
+        emit_system(&mut world, EmitterTrigger::Always, None);
+        let mut scene = render_system(&world, starke.0);
+        scene.overlay = Some(format!("FPS: {}", current_fps));
+        if let Err(e) = render_to_file(&scene, &camera, WIDTH, HEIGHT, path) {
+            eprintln!("Konnte {} nicht schreiben: {}", path, e);
+        }
+        return;
+    }
 
-    // Richtungsvektoren basierend auf Y-Rotation (world_pos.4)
-    let mut dir_x = world_pos.4.cos();
-    let mut dir_z = world_pos.4.sin();
+    let mut window = Window::new(
+        "game_for_idk",
+        WIDTH,
+        HEIGHT,
+        WindowOptions::default(),
+    )
+    .unwrap_or_else(|e| {
+        panic!("Fehler beim Öffnen des Fensters: {}", e);
+    });
 
-    // seitliche Richtung (rechtwinklig zur Blickrichtung)
-    let mut side_x = -world_pos.4.sin();
-    let mut side_z = world_pos.4.cos();
-    
-    let mut particles_fire = partikel_lode(200, 20.0);
-    let mut particles_lomm = partikel_lode(150, 20.0);
+    let mut clock = FixedStep::new(SIM_HZ);
+    // Zustand vor dem letzten Schritt, zum Interpolieren
+    let mut prev_camera = camera;
+    let mut prev_starke = starke.0;
 
     // Haupt-Loop
-    while window.is_open() && !window.is_key_down(Key::Escape) {
-        zbuffer.fill(f32::MAX);
-        
-        // Pixel setzen (ein einfacher Farbverlauf)
-        for y in 0..HEIGHT {
-            for x in 0..WIDTH {
-                let brightness = starke.0;
+    while window.is_open() {
+        input.update(&window.get_keys());
+        if input.is_down(Action::Quit) {
+            break;
+        }
+
+        // Klick fängt die Maus, ReleaseMouse (Tab) lässt sie wieder los
+        if window.get_mouse_down(MouseButton::Left) && !mouse.captured() {
+            mouse.capture(true);
+            window.set_cursor_visibility(false);
+        }
+        if input.just_pressed(Action::ReleaseMouse) && mouse.captured() {
+            mouse.capture(false);
+            window.set_cursor_visibility(true);
+        }
+
+        // Maus wirkt sofort, nicht erst mit dem nächsten Schritt
+        let look = mouse.delta(window.get_mouse_pos(MouseMode::Pass));
+        mouse.apply(&mut camera, look);
+        mouse.apply(&mut prev_camera, look);
+
+        for _ in 0..clock.advance() {
+            let dt = clock.dt;
+            prev_camera = camera;
+            prev_starke = starke.0;
+            let speed = MOVE_SPEED * dt;
+            let turn = TURN_SPEED * dt;
+
+            // Richtungsvektoren basierend auf Y-Rotation, flach auf dem Boden
+            let forward = Vec3::new(camera.yaw.sin(), 0.0, camera.yaw.cos());
+            let side = Vec3::new(camera.yaw.cos(), 0.0, -camera.yaw.sin());
+
+            let walk = (forward * input.axis(Action::MoveBack, Action::MoveForward)
+                + side * input.axis(Action::StrafeLeft, Action::StrafeRight))
+                * speed;
+
+            let player = world.player;
+            let hits = if let Some(controller) = world.controllers.get_mut(player) {
+                if input.is_down(Action::Jump) {
+                    controller.jump();
+                }
+                character_system(&mut world, player, walk, dt)
+            } else {
+                // Flugmodus ohne Hitbox
+                let up = Vec3::UP * input.axis(Action::MoveDown, Action::Jump) * speed;
+                world.set_position(player, world.position(player) + walk + up);
+                Vec::new()
+            };
+            camera.position = world.position(player);
+            emit_system(&mut world, EmitterTrigger::Collision, Some(&hits));
+
+            // Pfeiltasten, falls keine Maus
+            camera.turn(
+                input.axis(Action::TurnLeft, Action::TurnRight) * turn,
+                input.axis(Action::LookUp, Action::LookDown) * turn,
+            );
 
-                let red = ((y as f32 / HEIGHT as f32) * 255.0 * brightness) as u32;
-                let green = 10;
-                let blue = ((1.0 - brightness) * 100.0 + 150.0) as u32;
+            // Zoom
+            camera.zoom(input.axis(Action::ZoomIn, Action::ZoomOut) * ZOOM_SPEED * dt);
 
-                buffer[y * WIDTH + x] = (red << 16) | (green << 8) | blue;
+            if camera.roll > PI {
+                camera.roll -= 2.0 * PI;
+            }
+            if camera.roll < -PI {
+                camera.roll += 2.0 * PI;
             }
-        }
-        let old_pos = (world_pos.0, world_pos.1, world_pos.2);
-
-        if window.is_key_down(Key::S) {
-            world_pos.0 += dir_x * speed;
-            world_pos.2 += dir_z * speed;
-        }
-        if window.is_key_down(Key::W) {
-            world_pos.0 -= dir_x * speed;
-            world_pos.2 -= dir_z * speed;
-        }
-        if window.is_key_down(Key::A) {
-            world_pos.0 -= side_x * speed;
-            world_pos.2 -= side_z * speed;
-        }
-        if window.is_key_down(Key::D) {
-            world_pos.0 += side_x * speed;
-            world_pos.2 += side_z * speed;
-        }
-
-        if window.is_key_down(Key::Space) {
-            world_pos.1 += speed;
-        }
-        if window.is_key_down(Key::Q) {
-            world_pos.1 -= speed;
-        }
-
-        if window.is_key_down(Key::Left) {
-            world_pos.4 += 0.1; 
-        }
-        if window.is_key_down(Key::Right) {
-            world_pos.4 -= 0.1;
-        }
-
-        if window.is_key_down(Key::Down) {
-            world_pos.3 += 0.1; 
-        }
-        if window.is_key_down(Key::Up) {
-            world_pos.3 -= 0.1;
-        }
-
-        if world_pos.4 > PI {
-            world_pos.4 -= 2.0 * PI;
-        }
-        if world_pos.4 < -PI {
-            world_pos.4 += 2.0 * PI;
-        } 
-
-        if world_pos.5 > PI {
-            world_pos.5 -= 2.0 * PI;
-        }
-        if world_pos.5 < -PI {
-            world_pos.5 += 2.0 * PI;
-        } 
-
-        // Richtungsvektoren basierend auf Y-Rotation (world_pos.4)
-        dir_x = world_pos.4.sin();
-        dir_z = -world_pos.4.cos();
-
-        // seitliche Richtung (rechtwinklig zur Blickrichtung)
-        side_x = world_pos.4.cos();
-        side_z = world_pos.4.sin();
-
 
-        if window.is_key_down(Key::F) {
-            particles_fire.extend(partikel_lode(250, 40.0).into_iter().map(|mut p| {
+            if input.is_down(Action::FireParticles) {
                 // Ursprung bei Spieler
-                p.x = world_pos.0;
-                p.y = world_pos.1;
-                p.z = world_pos.2+1.0;
-                p
-            }));
-        }
-
-
-        if let (Some(player_hitbox), Some(cube_hitbox)) = &(&player, &cube.4) {
-            let kollidiert = check_aabb_collision(
-                player_hitbox,
-                cube_hitbox,
-                (world_pos.0, world_pos.1, world_pos.2),  // Position des Würfels
-                cube.1 // Position der Platte
-            );
-
-            if kollidiert {
-                world_pos.0 = old_pos.0;
-                world_pos.1 = old_pos.1;
-                world_pos.2 = old_pos.2;
+                emit_system(&mut world, EmitterTrigger::Fire, None);
             }
-        }
-
-        if let (Some(player_hitbox), Some(trasch_hitbox)) = &(&player, &trasch.4) {
-            let kollidiert = check_aabb_collision(
-                player_hitbox,
-                trasch_hitbox,
-                (world_pos.0, world_pos.1, world_pos.2),  // Position des Würfels
-                trasch.1 // Position der Platte
-            );
 
-            if kollidiert {
-                world_pos.0 = old_pos.0;
-                world_pos.1 = old_pos.1;
-                world_pos.2 = old_pos.2;
+            emit_system(&mut world, EmitterTrigger::Always, None);
+            particle_system(&mut world, &camera, dt);
 
-                particles_lomm.extend(partikel_lode(150, 20.0).into_iter().map(|mut p| {
-                    // Ursprung bei Spieler
-                    p.x = trasch.1.0;
-                    p.y = trasch.1.1;
-                    p.z = trasch.1.2+1.0;
-                    p
-                }));
+            if starke.0 < 0.0 {
+                starke.1 = false;
+            }
+            if starke.0 > 1.0 {
+                starke.1 = true;
+            }
+            if starke.1 {
+                starke.0 -= DAY_SPEED * dt;
+            }
+            if !starke.1 {
+                starke.0 += DAY_SPEED * dt;
             }
         }
 
-        // Buffer anzeigen
-        reader(&cube.0, world_pos, cube.1, cube.2, &mut buffer, &mut zbuffer, WIDTH, HEIGHT, &cube.5, light_dir, starke.0);
-        reader(&plate.0, world_pos, plate.1, plate.2, &mut buffer, &mut zbuffer, WIDTH, HEIGHT, &plate.5, light_dir, starke.0);
-        reader(&trasch.0, world_pos, trasch.1, trasch.2, &mut buffer, &mut zbuffer, WIDTH, HEIGHT, &trasch.5, light_dir, starke.0);
-        rander_partikel(
-            &mut particles_fire,
-            &mut buffer,
-            &mut zbuffer,
-            WIDTH,
-            HEIGHT,
-            (world_pos.0, world_pos.1, world_pos.2),
-            (world_pos.4, world_pos.3), // yaw, pitch
-            (60.0, 60.0, 60.0),
-            255.0,
-            100.0,
-            50.0,
-        );
-        rander_partikel(
-            &mut particles_lomm,
-            &mut buffer,
-            &mut zbuffer,
-            WIDTH,
-            HEIGHT,
-            (world_pos.0, world_pos.1, world_pos.2),
-            (world_pos.4, world_pos.3), // yaw, pitch
-            (15.0, 15.0, 15.0),
-            50.0,
-            100.0,
-            255.0,
-        );
-
+        // Buffer anzeigen, zwischen den letzten beiden Schritten interpoliert
+        let alpha = clock.alpha();
+        let view_camera = prev_camera.lerp(&camera, alpha);
+        let strake = prev_starke + (starke.0 - prev_starke) * alpha;
         // for fps
         fps_counter += 1;
         if last_fps_time.elapsed().as_secs_f32() >= 1.0 {
@@ -257,30 +207,12 @@ fn main() {
         }
         fps_text = format!("FPS: {}", current_fps);
 
-        if starke.0 < 0.0 {
-            starke.1 = false;
-        }
-        if starke.0 > 1.0 {
-            starke.1 = true;
-        }
-        if starke.1 == true {
-            starke.0 -= 0.0005;
-        }
-        if starke.1 == false {
-            starke.0 += 0.0005;
-        }
-
-        t = starke.0;
-        light_color = (
-            0.9 * t + 0.1, // R
-            0.85 * t + 0.15, // G
-            0.6 + (1.0 - t) * 0.4, // B – mehr Blau bei Nacht
-        );
-
-        draw_Fps(10, 10, HEIGHT, WIDTH, &fps_text, 0xFFFFFF, &mut buffer);
+        let mut scene = render_system(&world, strake);
+        scene.overlay = Some(fps_text);
+        render_scene(&scene, &view_camera, &mut fb);
 
         window.
-            update_with_buffer(&buffer, WIDTH, HEIGHT)
+            update_with_buffer(&fb.buffer, WIDTH, HEIGHT)
             .unwrap();
     }
 }
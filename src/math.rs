@@ -0,0 +1,432 @@
+// math.rs
+//
+// Gemeinsame Mathe für Rendering und Kollision.
+// Konvention: y zeigt nach oben, Kamera schaut entlang +z, Matrizen sind zeilenweise
+// gespeichert und werden von links auf Spaltenvektoren angewendet (M * v).
+
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Punkt oder Richtung im Raum
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct Vec3 {
+    /// Nach rechts
+    pub x: f32,
+    /// Nach oben
+    pub y: f32,
+    /// Nach vorn
+    pub z: f32,
+}
+
+impl Vec3 {
+    /// Nullvektor
+    pub const ZERO: Vec3 = Vec3 { x: 0.0, y: 0.0, z: 0.0 };
+    /// Überall 1, z.B. neutrale Skalierung
+    pub const ONE: Vec3 = Vec3 { x: 1.0, y: 1.0, z: 1.0 };
+    /// Nach oben (+y)
+    pub const UP: Vec3 = Vec3 { x: 0.0, y: 1.0, z: 0.0 };
+
+    /// Aus drei Komponenten
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    /// Alle Komponenten `v`
+    pub fn splat(v: f32) -> Self {
+        Vec3 { x: v, y: v, z: v }
+    }
+
+    /// Skalarprodukt
+    pub fn dot(self, o: Vec3) -> f32 {
+        self.x * o.x + self.y * o.y + self.z * o.z
+    }
+
+    /// Kreuzprodukt
+    pub fn cross(self, o: Vec3) -> Vec3 {
+        Vec3 {
+            x: self.y * o.z - self.z * o.y,
+            y: self.z * o.x - self.x * o.z,
+            z: self.x * o.y - self.y * o.x,
+        }
+    }
+
+    /// Länge zum Quadrat, spart die Wurzel
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Länge
+    pub fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    /// Einheitsvektor; Nullvektor bleibt Nullvektor
+    pub fn normalize(self) -> Vec3 {
+        let len = self.length();
+        if len > 0.0 {
+            self / len
+        } else {
+            self
+        }
+    }
+
+    /// Linear nach `o`, `t` = 0..1
+    pub fn lerp(self, o: Vec3, t: f32) -> Vec3 {
+        self + (o - self) * t
+    }
+
+    /// Komponentenweises Produkt
+    pub fn mul_elem(self, o: Vec3) -> Vec3 {
+        Vec3::new(self.x * o.x, self.y * o.y, self.z * o.z)
+    }
+
+    /// Komponentenweises Minimum
+    pub fn min(self, o: Vec3) -> Vec3 {
+        Vec3::new(self.x.min(o.x), self.y.min(o.y), self.z.min(o.z))
+    }
+
+    /// Komponentenweises Maximum
+    pub fn max(self, o: Vec3) -> Vec3 {
+        Vec3::new(self.x.max(o.x), self.y.max(o.y), self.z.max(o.z))
+    }
+
+    /// Komponentenweiser Betrag
+    pub fn abs(self) -> Vec3 {
+        Vec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+    fn add(self, o: Vec3) -> Vec3 {
+        Vec3::new(self.x + o.x, self.y + o.y, self.z + o.z)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+    fn sub(self, o: Vec3) -> Vec3 {
+        Vec3::new(self.x - o.x, self.y - o.y, self.z - o.z)
+    }
+}
+
+impl Mul<f32> for Vec3 {
+    type Output = Vec3;
+    fn mul(self, s: f32) -> Vec3 {
+        Vec3::new(self.x * s, self.y * s, self.z * s)
+    }
+}
+
+impl Div<f32> for Vec3 {
+    type Output = Vec3;
+    fn div(self, s: f32) -> Vec3 {
+        Vec3::new(self.x / s, self.y / s, self.z / s)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+    fn neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, o: Vec3) {
+        *self = *self + o;
+    }
+}
+
+impl SubAssign for Vec3 {
+    fn sub_assign(&mut self, o: Vec3) {
+        *self = *self - o;
+    }
+}
+
+/// Einheitsquaternion für Rotationen
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quat {
+    /// Vektoranteil x
+    pub x: f32,
+    /// Vektoranteil y
+    pub y: f32,
+    /// Vektoranteil z
+    pub z: f32,
+    /// Skalaranteil
+    pub w: f32,
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Quat::IDENTITY
+    }
+}
+
+impl Quat {
+    /// Keine Drehung
+    pub const IDENTITY: Quat = Quat { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+
+    /// Drehung um `axis` um `angle` (rad)
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Quat {
+        let a = axis.normalize();
+        let (s, c) = (angle / 2.0).sin_cos();
+        Quat { x: a.x * s, y: a.y * s, z: a.z * s, w: c }
+    }
+
+    /// Euler-Winkel im Bogenmaß: erst Roll (z), dann Pitch (x), dann Yaw (y)
+    pub fn from_euler(pitch: f32, yaw: f32, roll: f32) -> Quat {
+        Quat::from_axis_angle(Vec3::UP, yaw)
+            * Quat::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), pitch)
+            * Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), roll)
+    }
+
+    /// Umgekehrte Drehung (bei Länge 1)
+    pub fn conjugate(self) -> Quat {
+        Quat { x: -self.x, y: -self.y, z: -self.z, w: self.w }
+    }
+
+    /// Auf Länge 1 bringen, Null wird zu `IDENTITY`
+    pub fn normalize(self) -> Quat {
+        let len = (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt();
+        if len > 0.0 {
+            Quat { x: self.x / len, y: self.y / len, z: self.z / len, w: self.w / len }
+        } else {
+            Quat::IDENTITY
+        }
+    }
+
+    /// Dreht `v`
+    pub fn rotate(self, v: Vec3) -> Vec3 {
+        let q = Vec3::new(self.x, self.y, self.z);
+        let t = q.cross(v) * 2.0;
+        v + t * self.w + q.cross(t)
+    }
+
+    /// Als Rotationsmatrix
+    pub fn to_mat4(self) -> Mat4 {
+        let Quat { x, y, z, w } = self;
+        Mat4 {
+            m: [
+                [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w), 0.0],
+                [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w), 0.0],
+                [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y), 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+}
+
+impl Mul for Quat {
+    type Output = Quat;
+    fn mul(self, o: Quat) -> Quat {
+        Quat {
+            w: self.w * o.w - self.x * o.x - self.y * o.y - self.z * o.z,
+            x: self.w * o.x + self.x * o.w + self.y * o.z - self.z * o.y,
+            y: self.w * o.y - self.x * o.z + self.y * o.w + self.z * o.x,
+            z: self.w * o.z + self.x * o.y - self.y * o.x + self.z * o.w,
+        }
+    }
+}
+
+/// 4x4-Matrix, zeilenweise, wirkt auf Spaltenvektoren
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Mat4 {
+    /// `m[zeile][spalte]`
+    pub m: [[f32; 4]; 4],
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Mat4::IDENTITY
+    }
+}
+
+impl Mat4 {
+    /// Einheitsmatrix
+    pub const IDENTITY: Mat4 = Mat4 {
+        m: [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+    };
+
+    /// Verschiebung um `t`
+    pub fn translation(t: Vec3) -> Mat4 {
+        let mut r = Mat4::IDENTITY;
+        r.m[0][3] = t.x;
+        r.m[1][3] = t.y;
+        r.m[2][3] = t.z;
+        r
+    }
+
+    /// Skalierung pro Achse
+    pub fn scale(s: Vec3) -> Mat4 {
+        let mut r = Mat4::IDENTITY;
+        r.m[0][0] = s.x;
+        r.m[1][1] = s.y;
+        r.m[2][2] = s.z;
+        r
+    }
+
+    /// Translation * Rotation * Skalierung
+    pub fn trs(t: Vec3, r: Quat, s: Vec3) -> Mat4 {
+        Mat4::translation(t) * r.to_mat4() * Mat4::scale(s)
+    }
+
+    /// View-Matrix: Kamera bei `eye`, schaut auf `target` (+z = Blickrichtung)
+    pub fn look_at(eye: Vec3, target: Vec3, up: Vec3) -> Mat4 {
+        let f = (target - eye).normalize();
+        let r = up.cross(f).normalize();
+        let u = f.cross(r);
+
+        Mat4 {
+            m: [
+                [r.x, r.y, r.z, -r.dot(eye)],
+                [u.x, u.y, u.z, -u.dot(eye)],
+                [f.x, f.y, f.z, -f.dot(eye)],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Perspektive mit vertikalem `fov` in Grad. Tiefe nach Division in 0..1, w = z.
+    pub fn perspective(fov: f32, aspect: f32, near: f32, far: f32) -> Mat4 {
+        let f = 1.0 / (fov.to_radians() / 2.0).tan();
+        let range = far - near;
+
+        Mat4 {
+            m: [
+                [f / aspect, 0.0, 0.0, 0.0],
+                [0.0, f, 0.0, 0.0],
+                [0.0, 0.0, far / range, -near * far / range],
+                [0.0, 0.0, 1.0, 0.0],
+            ],
+        }
+    }
+
+    /// Zeilen und Spalten getauscht
+    pub fn transpose(&self) -> Mat4 {
+        let mut r = Mat4::IDENTITY;
+        for i in 0..4 {
+            for j in 0..4 {
+                r.m[i][j] = self.m[j][i];
+            }
+        }
+        r
+    }
+
+    /// `M * v` für homogene Koordinaten
+    pub fn transform_vec4(&self, v: [f32; 4]) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        for (i, row) in self.m.iter().enumerate() {
+            out[i] = row[0] * v[0] + row[1] * v[1] + row[2] * v[2] + row[3] * v[3];
+        }
+        out
+    }
+
+    /// Punkt (w = 1), ohne Perspektiv-Division
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        let [x, y, z, _] = self.transform_vec4([p.x, p.y, p.z, 1.0]);
+        Vec3::new(x, y, z)
+    }
+
+    /// Richtung (w = 0), Translation wird ignoriert
+    pub fn transform_vector(&self, v: Vec3) -> Vec3 {
+        let [x, y, z, _] = self.transform_vec4([v.x, v.y, v.z, 0.0]);
+        Vec3::new(x, y, z)
+    }
+
+    /// Allgemeine Inverse, `None` wenn die Matrix singulär ist
+    pub fn inverse(&self) -> Option<Mat4> {
+        // Gauß-Jordan auf [M | I]
+        let mut a = self.m;
+        let mut inv = Mat4::IDENTITY.m;
+
+        for col in 0..4 {
+            let pivot = (col..4)
+                .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+                .unwrap();
+            if a[pivot][col].abs() < 1e-8 {
+                return None;
+            }
+            a.swap(col, pivot);
+            inv.swap(col, pivot);
+
+            let p = a[col][col];
+            for j in 0..4 {
+                a[col][j] /= p;
+                inv[col][j] /= p;
+            }
+
+            for row in 0..4 {
+                if row != col {
+                    let f = a[row][col];
+                    for j in 0..4 {
+                        a[row][j] -= f * a[col][j];
+                        inv[row][j] -= f * inv[col][j];
+                    }
+                }
+            }
+        }
+
+        Some(Mat4 { m: inv })
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Mat4;
+    fn mul(self, o: Mat4) -> Mat4 {
+        let mut r = [[0.0; 4]; 4];
+        for (i, row) in r.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..4).map(|k| self.m[i][k] * o.m[k][j]).sum();
+            }
+        }
+        Mat4 { m: r }
+    }
+}
+
+/// Position, Rotation und Skalierung eines Objekts in der Welt
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    /// Verschiebung
+    pub position: Vec3,
+    /// Drehung um den eigenen Ursprung
+    pub rotation: Quat,
+    /// Größe pro Achse, vor der Drehung
+    pub scale: Vec3,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::new(Vec3::ZERO)
+    }
+}
+
+impl Transform {
+    /// Nur verschoben, nicht gedreht oder skaliert
+    pub fn new(position: Vec3) -> Self {
+        Transform {
+            position,
+            rotation: Quat::IDENTITY,
+            scale: Vec3::ONE,
+        }
+    }
+
+    /// Objekt -> Welt
+    pub fn matrix(&self) -> Mat4 {
+        Mat4::trs(self.position, self.rotation, self.scale)
+    }
+
+    /// Für Normalen: inverse Transponierte, damit nicht-uniforme Skalierung sie nicht verbiegt
+    pub fn normal_matrix(&self) -> Mat4 {
+        let inv_scale = Vec3::new(1.0 / self.scale.x, 1.0 / self.scale.y, 1.0 / self.scale.z);
+        self.rotation.to_mat4() * Mat4::scale(inv_scale)
+    }
+
+    /// Normale in Weltkoordinaten, Länge 1
+    pub fn transform_normal(&self, n: Vec3) -> Vec3 {
+        self.normal_matrix().transform_vector(n).normalize()
+    }
+}
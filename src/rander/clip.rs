@@ -0,0 +1,97 @@
+// rander/clip.rs
+
+/// Vertex im Clip-Space (x, y, z, w) vor der Perspektiv-Division,
+/// dazu `N` Attribute (Normale, Farbe, ...), die beim Schneiden mitinterpoliert werden.
+#[derive(Copy, Clone, Debug)]
+pub struct ClipVertex<const N: usize> {
+    /// Position im Clip-Space
+    pub pos: [f32; 4],
+    /// Attribute, linear mitgeschnitten
+    pub varying: [f32; N],
+}
+
+impl<const N: usize> ClipVertex<N> {
+    fn lerp(&self, other: &ClipVertex<N>, t: f32) -> ClipVertex<N> {
+        let pos = std::array::from_fn(|i| self.pos[i] + (other.pos[i] - self.pos[i]) * t);
+        let varying = std::array::from_fn(|i| self.varying[i] + (other.varying[i] - self.varying[i]) * t);
+        ClipVertex { pos, varying }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+/// Eine Ebene des Sichtvolumens
+pub enum ClipPlane {
+    /// z = 0
+    Near,
+    /// z = w
+    Far,
+    /// x = -w
+    Left,
+    /// x = w
+    Right,
+    /// y = -w
+    Bottom,
+    /// y = w
+    Top,
+}
+
+impl ClipPlane {
+    /// Vorzeichenbehafteter Abstand, >= 0 heißt innerhalb
+    fn distance<const N: usize>(&self, v: &ClipVertex<N>) -> f32 {
+        let [x, y, z, w] = v.pos;
+        match *self {
+            ClipPlane::Near => z,
+            ClipPlane::Far => w - z,
+            ClipPlane::Left => w + x,
+            ClipPlane::Right => w - x,
+            ClipPlane::Bottom => w + y,
+            ClipPlane::Top => w - y,
+        }
+    }
+}
+
+/// Alle sechs Ebenen des Sichtvolumens (Tiefe 0..w, siehe `Camera::projection`)
+pub fn frustum_planes() -> [ClipPlane; 6] {
+    [
+        ClipPlane::Near,
+        ClipPlane::Far,
+        ClipPlane::Left,
+        ClipPlane::Right,
+        ClipPlane::Bottom,
+        ClipPlane::Top,
+    ]
+}
+
+/// Sutherland-Hodgman: schneidet das Polygon nacheinander an jeder Ebene ab.
+/// Neue Eckpunkte werden zwischen den Kantenenden interpoliert.
+pub fn clip_polygon<const N: usize>(vertices: &[ClipVertex<N>], planes: &[ClipPlane]) -> Vec<ClipVertex<N>> {
+    let mut output: Vec<ClipVertex<N>> = vertices.to_vec();
+
+    for plane in planes {
+        if output.is_empty() {
+            break;
+        }
+
+        let input = std::mem::take(&mut output);
+
+        for i in 0..input.len() {
+            let current = &input[i];
+            let next = &input[(i + 1) % input.len()];
+
+            let d_current = plane.distance(current);
+            let d_next = plane.distance(next);
+
+            if d_current >= 0.0 {
+                output.push(*current);
+            }
+
+            // Kante kreuzt die Ebene -> Schnittpunkt einfügen
+            if (d_current >= 0.0) != (d_next >= 0.0) {
+                let t = d_current / (d_current - d_next);
+                output.push(current.lerp(next, t));
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,41 @@
+// rander/fehler.rs
+
+use std::fmt;
+
+/// Fehler beim Laden einer Datei (OBJ, MTL, Textur) mit Ort und Grund
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadError {
+    /// Datei, die nicht geladen werden konnte
+    pub path: String,
+    /// Zeile (1-basiert), `None` wenn die Datei als Ganzes kaputt ist
+    pub line: Option<usize>,
+    /// Was nicht gepasst hat
+    pub reason: String,
+}
+
+impl LoadError {
+    /// Fehler in `path`, optional mit Zeile
+    pub fn new(path: &str, line: Option<usize>, reason: impl Into<String>) -> Self {
+        LoadError {
+            path: path.to_string(),
+            line,
+            reason: reason.into(),
+        }
+    }
+
+    /// Fehler in einer bestimmten Zeile
+    pub fn at(path: &str, line: usize, reason: impl Into<String>) -> Self {
+        LoadError::new(path, Some(line), reason)
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.path, line, self.reason),
+            None => write!(f, "{}: {}", self.path, self.reason),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
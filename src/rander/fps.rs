@@ -25,7 +25,7 @@ const CHAR_FONT: [(char, [u8; FONT_HEIGHT]); 5] = [
     (' ', [0b000, 0b000, 0b000, 0b000, 0b000]),
 ];
 
-fn draw_digit(x: usize, y: usize, HEIGHT: usize, WIDTH: usize, digit: u8, color: u32, buffer: &mut [u32]) {
+fn draw_digit(x: usize, y: usize, height: usize, width: usize, digit: u8, color: u32, buffer: &mut [u32]) {
     if digit > 9 {
         return;
     }
@@ -37,23 +37,23 @@ fn draw_digit(x: usize, y: usize, HEIGHT: usize, WIDTH: usize, digit: u8, color:
             if (row >> (FONT_WIDTH - 1 - col)) & 1 == 1 {
                 let px = x + col;
                 let py = y + row_idx;
-                if px < WIDTH && py < HEIGHT {
-                    buffer[py * WIDTH + px] = color;
+                if px < width && py < height {
+                    buffer[py * width + px] = color;
                 }
             }
         }
     }
 }
 
-fn draw_char(x: usize, y: usize, HEIGHT: usize, WIDTH: usize, ch: char, color: u32, buffer: &mut [u32]) {
+fn draw_char(x: usize, y: usize, height: usize, width: usize, ch: char, color: u32, buffer: &mut [u32]) {
     if let Some((_, glyph)) = CHAR_FONT.iter().find(|(c, _)| *c == ch) {
         for (row_idx, row) in glyph.iter().enumerate() {
             for col in 0..FONT_WIDTH {
                 if (row >> (FONT_WIDTH - 1 - col)) & 1 == 1 {
                     let px = x + col;
                     let py = y + row_idx;
-                    if px < WIDTH && py < HEIGHT {
-                        buffer[py * WIDTH + px] = color;
+                    if px < width && py < height {
+                        buffer[py * width + px] = color;
                     }
                 }
             }
@@ -61,12 +61,16 @@ fn draw_char(x: usize, y: usize, HEIGHT: usize, WIDTH: usize, ch: char, color: u
     }
 }
 
-pub fn draw_Fps(mut x: usize, y: usize, HEIGHT: usize, WIDTH: usize, text: &str, color: u32, buffer: &mut [u32]) {
+/// Alter Name von `draw_fps`
+pub use self::draw_fps as draw_Fps;
+
+/// Text (Ziffern, "FPS: ") mit der eingebauten 3x5-Schrift in den Buffer schreiben
+pub fn draw_fps(mut x: usize, y: usize, height: usize, width: usize, text: &str, color: u32, buffer: &mut [u32]) {
     for ch in text.chars() {
         if ch.is_ascii_digit() {
-            draw_digit(x, y, HEIGHT, WIDTH, ch as u8 - b'0', color, buffer);
+            draw_digit(x, y, height, width, ch as u8 - b'0', color, buffer);
         } else {
-            draw_char(x, y, HEIGHT, WIDTH, ch, color, buffer);
+            draw_char(x, y, height, width, ch, color, buffer);
         }
         x += FONT_WIDTH + 1; // Abstand zwischen Zeichen
     }
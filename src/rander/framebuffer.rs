@@ -0,0 +1,86 @@
+// rander/framebuffer.rs
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+/// Farb- und Tiefenpuffer für ein Bild, mit oder ohne Fenster
+pub struct Framebuffer {
+    /// Breite in Pixeln
+    pub width: usize,
+    /// Höhe in Pixeln
+    pub height: usize,
+    /// Farben als 0xFFRRGGBB (oberes Byte wird ignoriert), zeilenweise von oben
+    pub buffer: Vec<u32>,
+    /// Tiefe pro Pixel, kleiner ist näher
+    pub zbuffer: Vec<f32>,
+}
+
+impl Framebuffer {
+    /// Schwarzes Bild, Tiefe überall maximal
+    pub fn new(width: usize, height: usize) -> Self {
+        Framebuffer {
+            width,
+            height,
+            buffer: vec![0; width * height],
+            zbuffer: vec![f32::MAX; width * height],
+        }
+    }
+
+    /// Mit `color` füllen und die Tiefe zurücksetzen
+    pub fn clear(&mut self, color: u32) {
+        self.buffer.fill(color);
+        self.zbuffer.fill(f32::MAX);
+    }
+
+    /// Himmel als Farbverlauf, `brightness` 0 = Nacht, 1 = Tag
+    pub fn draw_sky(&mut self, brightness: f32) {
+        self.zbuffer.fill(f32::MAX);
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let red = ((y as f32 / self.height as f32) * 255.0 * brightness) as u32;
+                let green = 10;
+                let blue = ((1.0 - brightness) * 100.0 + 150.0) as u32;
+
+                self.buffer[y * self.width + x] = (red << 16) | (green << 8) | blue;
+            }
+        }
+    }
+
+    /// Pixel als RGB-Bytes, zeilenweise von oben
+    pub fn to_rgb8(&self) -> Vec<u8> {
+        self.buffer
+            .iter()
+            .flat_map(|c| [(c >> 16) as u8, (c >> 8) as u8, *c as u8])
+            .collect()
+    }
+
+    /// Speichert als PNG oder PPM, je nach Dateiendung
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        if path.to_lowercase().ends_with(".ppm") {
+            self.save_ppm(path)
+        } else {
+            self.save_png(path)
+        }
+    }
+
+    /// Als PPM (P6) speichern
+    pub fn save_ppm(&self, path: &str) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.to_rgb8())?;
+        out.flush()
+    }
+
+    /// Als PNG speichern
+    pub fn save_png(&self, path: &str) -> io::Result<()> {
+        let out = BufWriter::new(File::create(path)?);
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&self.to_rgb8()).map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+}
@@ -0,0 +1,119 @@
+// rander/kamera.rs
+
+use std::f32::consts::PI;
+use crate::math::{Mat4, Quat, Vec3};
+
+/// Perspektivische Kamera. Kamerakoordinaten: x rechts, y oben, z in Blickrichtung.
+#[derive(Copy, Clone, Debug)]
+pub struct Camera {
+    /// Augpunkt in der Welt
+    pub position: Vec3,
+    /// Um y (rad), positiv = nach rechts drehen
+    pub yaw: f32,
+    /// Um x (rad), positiv = nach unten schauen
+    pub pitch: f32,
+    /// Um z (rad)
+    pub roll: f32,
+    /// Vertikales Sichtfeld in Grad
+    pub fov: f32,
+    /// Breite durch Höhe
+    pub aspect: f32,
+    /// Abstand der Near-Plane
+    pub near: f32,
+    /// Abstand der Far-Plane
+    pub far: f32,
+}
+
+/// Weiter nach oben/unten geht es nicht, sonst steht das Bild auf dem Kopf
+pub const MAX_PITCH: f32 = 89.0 * PI / 180.0;
+
+impl Camera {
+    /// Im Ursprung, schaut nach +z
+    pub fn new(fov: f32, aspect: f32, near: f32, far: f32) -> Self {
+        Camera {
+            position: Vec3::ZERO,
+            yaw: 0.0,
+            pitch: 0.0,
+            roll: 0.0,
+            fov,
+            aspect,
+            near,
+            far,
+        }
+    }
+
+    /// Drehung aus yaw, pitch und roll
+    pub fn rotation(&self) -> Quat {
+        Quat::from_euler(self.pitch, self.yaw, self.roll)
+    }
+
+    /// Blickrichtung in Weltkoordinaten
+    pub fn forward(&self) -> Vec3 {
+        self.rotation().rotate(Vec3::new(0.0, 0.0, 1.0))
+    }
+
+    /// Nach rechts im Bild
+    pub fn right(&self) -> Vec3 {
+        self.rotation().rotate(Vec3::new(1.0, 0.0, 0.0))
+    }
+
+    /// Yaw und Pitch so setzen, dass die Kamera auf `target` schaut (Roll = 0)
+    pub fn look_at(&mut self, target: Vec3) {
+        let d = (target - self.position).normalize();
+        self.yaw = d.x.atan2(d.z);
+        self.pitch = (-d.y).clamp(-1.0, 1.0).asin();
+        self.roll = 0.0;
+    }
+
+    /// Welt -> Kamerakoordinaten
+    pub fn view(&self) -> Mat4 {
+        self.rotation().conjugate().to_mat4() * Mat4::translation(-self.position)
+    }
+
+    /// Projektionsmatrix. Tiefe landet nach der Division in 0..1, w = z.
+    pub fn projection(&self) -> Mat4 {
+        Mat4::perspective(self.fov, self.aspect, self.near, self.far)
+    }
+
+    /// Punkt in Kamerakoordinaten -> Clip-Space
+    pub fn to_clip(&self, v: Vec3) -> [f32; 4] {
+        self.projection().transform_vec4([v.x, v.y, v.z, 1.0])
+    }
+
+    /// Clip-Space -> Pixelkoordinaten (y nach unten)
+    pub fn to_screen(&self, clip: [f32; 4], width: usize, height: usize) -> (f32, f32) {
+        let [x, y, _, w] = clip;
+
+        let sx = (x / w + 1.0) * 0.5 * width as f32;
+        let sy = (1.0 - y / w) * 0.5 * height as f32;
+
+        (sx, sy)
+    }
+
+    /// Drehen um `yaw` / `pitch` (rad). Yaw bleibt in -PI..PI, Pitch in ±89°.
+    pub fn turn(&mut self, yaw: f32, pitch: f32) {
+        self.yaw = (self.yaw + yaw + PI).rem_euclid(2.0 * PI) - PI;
+        self.pitch = (self.pitch + pitch).clamp(-MAX_PITCH, MAX_PITCH);
+    }
+
+    /// Zwischen zwei Kamerazuständen überblenden, Winkel über den kürzeren Weg
+    pub fn lerp(&self, other: &Camera, t: f32) -> Camera {
+        let angle = |a: f32, b: f32| {
+            let d = (b - a + PI).rem_euclid(2.0 * PI) - PI;
+            a + d * t
+        };
+        Camera {
+            position: self.position.lerp(other.position, t),
+            yaw: angle(self.yaw, other.yaw),
+            pitch: angle(self.pitch, other.pitch),
+            roll: angle(self.roll, other.roll),
+            fov: self.fov + (other.fov - self.fov) * t,
+            ..*other
+        }
+    }
+
+    /// Zoom über das Sichtfeld, negativ = reinzoomen
+    pub fn zoom(&mut self, delta: f32) {
+        self.fov = (self.fov + delta).clamp(10.0, 120.0);
+    }
+}
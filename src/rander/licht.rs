@@ -1,30 +1,39 @@
-use crate::rander::rander_model::Vec3;
-use crate::rander::rander_model::rgb;
+use crate::math::Vec3;
+use crate::rander::rander_model::Material;
 
-pub fn vec3_dot(a: Vec3, b: Vec3) -> f32 {
-    a.x * b.x + a.y * b.y + a.z * b.z
-}
-
-pub fn vec3_normalize(v: Vec3) -> Vec3 {
-    let len = (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
-    Vec3 {
-        x: v.x / len,
-        y: v.y / len,
-        z: v.z / len,
+/// Licht an einem Punkt, noch ohne Oberflächenfarbe:
+/// `diffuse` wird mit Albedo multipliziert, `specular` einfach addiert.
+pub fn light_terms(material: &Material, normal: Vec3, view_dir: Vec3, light_dir: Vec3, strake: f32) -> (Vec3, Vec3) {
+    // illum 0: nur Farbe, kein Licht
+    if material.illum == 0 {
+        return (Vec3::ONE, Vec3::ZERO);
     }
-}
 
-pub fn apply_light(color: u32, normal: Vec3, light_dir: Vec3, strake: f32) -> u32 {
-    let n = vec3_normalize(normal);
-    let l = vec3_normalize(light_dir);
-    let mut intensity = vec3_dot(n, l).max(0.0);
+    let n = normal.normalize();
+    let l = light_dir.normalize();
+    let intensity = n.dot(l).max(0.0);
+
+    // Umgebungslicht (Ka) wächst mit der Tageszeit
+    let ambient = material.ambient * strake;
+    let diffuse = (ambient + Vec3::splat(intensity)).min(Vec3::ONE);
 
-    // Optional: ambient light
-    intensity = (intensity + strake).min(1.0);
+    // illum >= 2: Glanzlicht nach Blinn-Phong
+    let mut specular = Vec3::ZERO;
+    if material.illum >= 2 && intensity > 0.0 {
+        let h = (l + view_dir.normalize()).normalize();
+        specular = material.specular * n.dot(h).max(0.0).powf(material.shininess.max(1.0));
+    }
+
+    (diffuse, specular)
+}
 
-    let r = ((color >> 16) & 0xFF) as f32 * intensity;
-    let g = ((color >> 8) & 0xFF) as f32 * intensity;
-    let b = (color & 0xFF) as f32 * intensity;
+/// Endfarbe (linear, 0..1) aus Licht und Albedo
+pub fn compose(material: &Material, albedo: Vec3, diffuse: Vec3, specular: Vec3) -> Vec3 {
+    (material.emission + albedo.mul_elem(diffuse) + specular).min(Vec3::ONE)
+}
 
-    rgb(r.min(255.0) as u8, g.min(255.0) as u8, b.min(255.0) as u8)
+/// Blinn-Phong für einen Punkt: Licht berechnen und mit der Grundfarbe verrechnen
+pub fn apply_light(material: &Material, albedo: Vec3, normal: Vec3, view_dir: Vec3, light_dir: Vec3, strake: f32) -> Vec3 {
+    let (diffuse, specular) = light_terms(material, normal, view_dir, light_dir, strake);
+    compose(material, albedo, diffuse, specular)
 }
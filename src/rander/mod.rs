@@ -1,4 +1,22 @@
+//! Software-Renderer: Modelle laden, Kamera, Rasterizer, Licht und Partikel.
+
+/// OBJ/MTL laden und Meshes zeichnen (`reader`)
 pub mod rander_model;
+/// Partikel bewegen und zeichnen
 pub mod partikel;
+/// Text aus eingebauter Pixel-Schrift, z.B. die FPS
 pub mod fps;
+/// Licht und Materialfarben
 pub mod licht;
+/// Polygone am Sichtvolumen abschneiden
+pub mod clip;
+/// Kamera mit Projektion
+pub mod kamera;
+/// Texturen (PNG, PPM)
+pub mod textur;
+/// Ladefehler mit Datei und Zeile
+pub mod fehler;
+/// Farb- und Tiefenpuffer, als Bild speichern
+pub mod framebuffer;
+/// Ganze Bilder zeichnen, auch ohne Fenster
+pub mod offscreen;
@@ -0,0 +1,80 @@
+// rander/offscreen.rs
+
+use std::collections::HashMap;
+use std::io;
+use crate::math::{Transform, Vec3};
+use crate::rander::fps::draw_fps;
+use crate::rander::framebuffer::Framebuffer;
+use crate::rander::kamera::Camera;
+use crate::rander::partikel::{rander_partikel, Particle};
+use crate::rander::rander_model::{reader, Material, Model};
+
+/// Ein gezeichnetes Objekt: Mesh, Lage und Materialien
+pub struct SceneObject<'a> {
+    /// Zu zeichnendes Mesh
+    pub model: &'a Model,
+    /// Lage in der Welt
+    pub transform: Transform,
+    /// Materialien nach Name
+    pub materials: &'a HashMap<String, Material>,
+}
+
+/// Partikel einer Quelle in einer Farbe (0..255)
+pub struct ParticleCloud<'a> {
+    /// Lebende Partikel
+    pub particles: &'a [Particle],
+    /// Farbe in 0..255
+    pub color: [f32; 3],
+}
+
+/// Alles, was für ein Bild gebraucht wird: Objekte, Partikel, Licht und Text
+pub struct Scene<'a> {
+    /// Meshes
+    pub objects: Vec<SceneObject<'a>>,
+    /// Partikel, nach den Meshes gezeichnet
+    pub particles: Vec<ParticleCloud<'a>>,
+    /// Richtung zum Licht
+    pub light_dir: Vec3,
+    /// Tageszeit 0..1
+    pub strake: f32,
+    /// Text oben links, z.B. die FPS
+    pub overlay: Option<String>,
+}
+
+/// Ein ganzes Bild in den Framebuffer zeichnen, wie im Fenster:
+/// Himmel, Objekte, Partikel, zuletzt der Text.
+pub fn render_scene(scene: &Scene, camera: &Camera, fb: &mut Framebuffer) {
+    fb.draw_sky(scene.strake);
+
+    for object in &scene.objects {
+        reader(
+            object.model,
+            camera,
+            &object.transform,
+            &mut fb.buffer,
+            &mut fb.zbuffer,
+            fb.width,
+            fb.height,
+            object.materials,
+            scene.light_dir,
+            scene.strake,
+        );
+    }
+
+    for cloud in &scene.particles {
+        let [r, g, b] = cloud.color;
+        rander_partikel(cloud.particles, &mut fb.buffer, &mut fb.zbuffer, fb.width, fb.height, camera, r, g, b);
+    }
+
+    if let Some(text) = &scene.overlay {
+        draw_fps(10, 10, fb.height, fb.width, text, 0xFFFFFF, &mut fb.buffer);
+    }
+}
+
+/// Ohne Fenster rendern und als PNG/PPM speichern
+pub fn render_to_file(scene: &Scene, camera: &Camera, width: usize, height: usize, path: &str) -> io::Result<Framebuffer> {
+    let mut fb = Framebuffer::new(width, height);
+    render_scene(scene, camera, &mut fb);
+    fb.save(path)?;
+    Ok(fb)
+}
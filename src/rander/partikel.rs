@@ -1,28 +1,31 @@
 // rander/partiklel.rs
 
 use rand::{random};
+use crate::rander::kamera::Camera;
+use crate::math::{Mat4, Vec3};
 
+/// Ein Partikel in Weltkoordinaten, `life` zählt bis 0 herunter
 pub struct Particle {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub dx: f32,
-    pub dy: f32,
-    pub dz: f32,
+    /// Position in der Welt
+    pub pos: Vec3,
+    /// Geschwindigkeit pro Referenz-Bild
+    pub vel: Vec3,
+    /// Stirbt bei 0
     pub life: f32,
 }
 
+/// Erzeugt `anzahl` Partikel mit zufälliger Geschwindigkeit am Ursprung
 pub fn partikel_lode(anzahl: usize, life: f32) -> Vec<Particle> {
     let mut particles = Vec::new();
 
     for _ in 0..anzahl {
         particles.push(Particle {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-            dx: (random::<f32>() - 0.5) * 0.5,
-            dy: (random::<f32>() - 0.5) * 0.5,
-            dz: (random::<f32>() - 0.5) * 0.5,
+            pos: Vec3::ZERO,
+            vel: Vec3::new(
+                (random::<f32>() - 0.5) * 0.5,
+                (random::<f32>() - 0.5) * 0.5,
+                (random::<f32>() - 0.5) * 0.5,
+            ),
             life,
         });
     }
@@ -30,88 +33,88 @@ pub fn partikel_lode(anzahl: usize, life: f32) -> Vec<Particle> {
     particles
 }
 
-fn lerp(a: u8, b: u8, t: f32) -> u8 {
-    ((a as f32) + (b as f32 - a as f32) * t).round() as u8
+/// Geschwindigkeit und Lebensdauer sind für 60 Bilder pro Sekunde ausgelegt
+const REFERENCE_FPS: f32 = 60.0;
+
+/// Partikel um `dt` Sekunden weiterbewegen und abgelaufene entfernen.
+/// Sichtbare Partikel weit weg von der Kamera sterben schneller.
+pub fn update_partikel(particles: &mut Vec<Particle>, moveing: (f32, f32, f32), camera: &Camera, dt: f32) {
+    let steps = dt * REFERENCE_FPS;
+    let view = camera.view();
+
+    particles.retain_mut(|p| {
+        // Move
+        let dt_x = steps / moveing.0.max(0.001);
+        let dt_y = steps / moveing.1.max(0.001);
+        let dt_z = steps / moveing.2.max(0.001);
+
+        p.pos += Vec3::new(p.vel.x * dt_x, p.vel.y * dt_y, p.vel.z * dt_z);
+        p.life -= 0.05 * steps;
+
+        if (p.pos - camera.position).length_squared() > 1000.0 && on_screen(camera, &view, p.pos) {
+            p.life -= 0.2 * steps;
+        }
+
+        p.life > 0.0
+    });
+}
+
+// Im Bild wie bei `rander_partikel`: vor der Near-Plane und im Sichtfeld
+fn on_screen(camera: &Camera, view: &Mat4, pos: Vec3) -> bool {
+    let v = view.transform_point(pos);
+    if v.z <= camera.near {
+        return false;
+    }
+    let [x, y, _, w] = camera.to_clip(v);
+    (x / w).abs() <= 1.0 && (y / w).abs() <= 1.0
 }
 
-/// Neue Version von `rander_partikel` – berücksichtigt Kamerarotation (yaw und pitch)
+/// Partikel als Quadrate zeichnen – nutzt View und Projektion der Kamera.
+/// Bewegt wird mit `update_partikel`.
+#[allow(clippy::too_many_arguments)]
 pub fn rander_partikel(
-    particles: &mut Vec<Particle>,
+    particles: &[Particle],
     buffer: &mut [u32],
     zbuffer: &mut [f32],
     width: usize,
     height: usize,
-    player_pos: (f32, f32, f32),
-    player_rot: (f32, f32), // (yaw, pitch)
-    moveing: (f32, f32, f32),
+    camera: &Camera,
     r: f32,
     g: f32,
     b: f32,
 ) {
-    let fov: f32 = 60.0;
-
-    let yaw = -player_rot.0;
-    let pitch = -player_rot.1;
-
-    let cos_yaw = yaw.cos();
-    let sin_yaw = yaw.sin();
-    let cos_pitch = pitch.cos();
-    let sin_pitch = pitch.sin();
-
-    particles.retain_mut(|p| {
-        // Move
-        let dt_x = 1.0 / moveing.0.max(0.001);
-        let dt_y = 1.0 / moveing.1.max(0.001);
-        let dt_z = 1.0 / moveing.2.max(0.001);
-
-        p.x += p.dx * dt_x;
-        p.y += p.dy * dt_y;
-        p.z += p.dz * dt_z;
-        p.life -= 0.05;
-
-        if p.life <= 0.0 {
-            return false;
-        }
+    let view = camera.view();
 
+    for p in particles {
         // Relative to camera
-        let rel_x = p.x - player_pos.0;
-        let rel_y = p.y - player_pos.1;
-        let rel_z = p.z - player_pos.2;
+        let rel = p.pos - camera.position;
 
-        // Rotate around Y (Yaw)
-        let x1 = rel_x * cos_yaw - rel_z * sin_yaw;
-        let z1 = rel_x * sin_yaw + rel_z * cos_yaw;
+        // In Kamerakoordinaten
+        let v = view.transform_point(p.pos);
+        let z2 = v.z;
 
-        // Rotate around X (Pitch)
-        let y1 = rel_y * cos_pitch - z1 * sin_pitch;
-        let z2 = rel_y * sin_pitch + z1 * cos_pitch;
+        // Gleiche Projektion wie die Modelle
+        let clip = camera.to_clip(v);
 
-        let fov_rad = (fov.to_radians() / 2.0).tan();
-        let aspect_ratio = width as f32 / height as f32;
-
-        if z2 <= 0.1 {
-            return true;
+        if z2 <= camera.near {
+            continue;
         }
 
-        let px = x1 / (z2 * fov_rad * aspect_ratio);
-        let py = y1 / (z2 * fov_rad);
+        let px = clip[0] / clip[3];
+        let py = clip[1] / clip[3];
 
         if px.abs() > 1.0 || py.abs() > 1.0 {
-            return true;
+            continue;
         }
 
-        let screen_x = ((px + 1.0) * 0.5 * width as f32).round() as isize;
-        let screen_y = ((1.0 - (py + 1.0) * 0.5) * height as f32).round() as isize;
+        let (sx, sy) = camera.to_screen(clip, width, height);
+        let screen_x = sx.round() as isize;
+        let screen_y = sy.round() as isize;
 
         let point_size = ((5.0 / z2).clamp(1.0, 4.0)) as isize;
 
-        let dist_sq = rel_x * rel_x + rel_y * rel_y + rel_z * rel_z;
-        if dist_sq > 1000.0 {
-            p.life -= 0.2;
-        }
-
-        if rel_x.abs() > 100.0 || rel_y.abs() > 100.0 || rel_z.abs() > 100.0 {
-            return true;
+        if rel.x.abs() > 100.0 || rel.y.abs() > 100.0 || rel.z.abs() > 100.0 {
+            continue;
         }
 
         for dy in -point_size..=point_size {
@@ -133,7 +136,5 @@ pub fn rander_partikel(
                 }
             }
         }
-
-        true // keep the particle
-    });
+    }
 }
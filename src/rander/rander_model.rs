@@ -2,79 +2,241 @@ use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::collections::HashMap;
 use crate::rander::licht::*;
+use crate::rander::clip::*;
+use crate::rander::kamera::Camera;
+use crate::math::Transform;
+use crate::rander::textur::{Texture, load_texture};
+use crate::rander::fehler::LoadError;
+use std::path::Path;
+use serde::Deserialize;
 
-#[derive(Copy, Clone, Debug)]
-pub struct Vec3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-}
+pub use crate::math::Vec3;
 
+/// Eckpunkt eines Faces, Indizes 0-basiert
 #[derive(Debug)]
 pub struct FaceVertex {
+    /// In `Model::vertices`
     pub vertex_index: usize,
+    /// In `Model::texcoords`
     pub texcoord_index: Option<usize>,
+    /// In `Model::normals`
     pub normal_index: Option<usize>,
 }
 
+/// Welche Seite eines Faces weggelassen wird
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum CullMode {
+    /// Doppelseitig, z.B. die Platte
+    None,
+    /// Rückseiten weglassen
+    #[default]
+    Back,
+    /// Vorderseiten weglassen
+    Front,
+}
+
+/// Reihenfolge der Eckpunkte, die als Vorderseite gilt (OBJ: gegen den Uhrzeigersinn)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Winding {
+    /// Gegen den Uhrzeigersinn (OBJ)
+    CounterClockwise,
+    /// Im Uhrzeigersinn
+    Clockwise,
+}
+
+/// Beleuchtung pro Face, pro Eckpunkt (Gouraud) oder pro Pixel (Phong)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum ShadingMode {
+    /// Eine Farbe pro Face, aus seiner Ebene
+    #[default]
+    Flat,
+    /// Licht pro Eckpunkt, Farbe interpoliert
+    Gouraud,
+    /// Normale interpoliert, Licht pro Pixel
+    Phong,
+}
+
+/// Polygon aus der OBJ
+#[derive(Debug)]
+pub struct Face {
+    /// Eckpunkte in Datei-Reihenfolge
+    pub vertices: Vec<FaceVertex>,
+    /// Name aus `usemtl`, leer ohne
+    pub material: String,
+    /// 0 = aus ("s off")
+    pub smoothing_group: u32,
+}
+
+/// Faces eines `o`-Objekts bzw. einer `g`-Gruppe (Indizes in `Model::faces`)
+#[derive(Debug)]
+pub struct Group {
+    /// Name aus `o`
+    pub object: String,
+    /// Name aus `g`
+    pub name: String,
+    /// Indizes in `Model::faces`
+    pub faces: Vec<usize>,
+}
+
+/// Geladenes OBJ-Modell mit Render-Einstellungen
 pub struct Model {
+    /// Positionen
     pub vertices: Vec<Vec3>,
+    /// Leer oder eine Farbe pro Vertex ("v x y z r g b")
+    pub colors: Vec<Vec3>,
+    /// UVs, v = 0 unten
     pub texcoords: Vec<(f32, f32)>,
+    /// Aus `vn` und für Smoothing-Groups berechnet
     pub normals: Vec<Vec3>,
-    pub faces: Vec<(Vec<FaceVertex>, String)>, // <--- Neue Struktur
+    /// Alle Polygone
+    pub faces: Vec<Face>,
+    /// Objekte und Gruppen
+    pub groups: Vec<Group>,
+    /// mtllib, relativ zur OBJ-Datei aufgelöst
+    pub material_libs: Vec<String>,
+    /// Kanten aus Faces und `l`-Linien als Vertex-Indizes
     pub edges: Vec<(usize, usize)>,
+    /// Welche Seite weggelassen wird
+    pub cull_mode: CullMode,
+    /// Was als Vorderseite gilt
+    pub front_face: Winding,
+    /// Wie beleuchtet wird
+    pub shading: ShadingMode,
 }
 
-pub fn load_obj(path: &str) -> Model {
-    let file = File::open(path).expect("Konnte Datei nicht öffnen");
+/// Zahl an Position `i` lesen, mit Zeilennummer im Fehlerfall
+fn parse_at<T: std::str::FromStr>(tokens: &[&str], i: usize, path: &str, line_no: usize) -> Result<T, LoadError> {
+    let token = tokens
+        .get(i)
+        .ok_or_else(|| LoadError::at(path, line_no, format!("'{}' braucht mindestens {} Werte", tokens[0], i)))?;
+    token
+        .parse()
+        .map_err(|_| LoadError::at(path, line_no, format!("ungültige Zahl '{}'", token)))
+}
+
+/// OBJ-Index (1-basiert, negativ = relativ zum Ende) in 0-basierten umwandeln und prüfen
+fn parse_index(s: &str, count: usize, path: &str, line_no: usize) -> Result<usize, LoadError> {
+    let i: isize = s
+        .parse()
+        .map_err(|_| LoadError::at(path, line_no, format!("ungültiger Index '{}'", s)))?;
+    let resolved = if i < 0 { count as isize + i } else { i - 1 };
+    if i == 0 || resolved < 0 || resolved >= count as isize {
+        return Err(LoadError::at(path, line_no, format!("Index {} passt nicht zu {} Einträgen", i, count)));
+    }
+    Ok(resolved as usize)
+}
+
+/// Lädt eine OBJ-Datei; Fehler enthalten Pfad und Zeile
+pub fn load_obj(path: &str) -> Result<Model, LoadError> {
+    let file = File::open(path).map_err(|e| LoadError::new(path, None, format!("Konnte Datei nicht öffnen: {}", e)))?;
     let reader = BufReader::new(file);
+    let dir = Path::new(path).parent().unwrap_or(Path::new(""));
 
     let mut vertices = Vec::new();
+    let mut colors: Vec<Vec3> = Vec::new();
     let mut edges = Vec::new();
     let mut faces = Vec::new();
+    let mut groups: Vec<Group> = Vec::new();
+    let mut material_libs = Vec::new();
     let mut texcoords = Vec::new();
     let mut normals = Vec::new();
 
     let mut current_material = String::new(); // martiral
-
-    for line in reader.lines() {
-        let line = line.unwrap();
-        let tokens: Vec<&str> = line.split_whitespace().collect();
+    let mut current_object = String::new();
+    let mut current_group = String::new();
+    let mut current_smoothing = 0;
+
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line_no = line_idx + 1;
+        let line = line.map_err(|e| LoadError::at(path, line_no, e.to_string()))?;
+        // Kommentare auch am Zeilenende
+        let content = line.split('#').next().unwrap_or("");
+        let tokens: Vec<&str> = content.split_whitespace().collect();
         if tokens.is_empty() { continue; }
 
         match tokens[0] {
             "v" => {
-                let x: f32 = tokens[1].parse().unwrap();
-                let y: f32 = tokens[2].parse().unwrap();
-                let z: f32 = tokens[3].parse().unwrap();
+                let x: f32 = parse_at(&tokens, 1, path, line_no)?;
+                let y: f32 = parse_at(&tokens, 2, path, line_no)?;
+                let z: f32 = parse_at(&tokens, 3, path, line_no)?;
                 vertices.push(Vec3 { x, y, z });
+
+                // Erweiterung: Vertex-Farbe hinter der Position
+                if tokens.len() >= 7 {
+                    let r: f32 = parse_at(&tokens, 4, path, line_no)?;
+                    let g: f32 = parse_at(&tokens, 5, path, line_no)?;
+                    let b: f32 = parse_at(&tokens, 6, path, line_no)?;
+                    // Vertices ohne Farbe davor sind weiß
+                    colors.resize(vertices.len() - 1, Vec3::ONE);
+                    colors.push(Vec3::new(r, g, b));
+                } else if !colors.is_empty() {
+                    colors.push(Vec3::ONE);
+                }
             }
             "vt" => {
-                let u: f32 = tokens[1].parse().unwrap();
-                let v: f32 = tokens[2].parse().unwrap();
+                let u: f32 = parse_at(&tokens, 1, path, line_no)?;
+                // "vt u" ist erlaubt
+                let v: f32 = if tokens.len() > 2 { parse_at(&tokens, 2, path, line_no)? } else { 0.0 };
                 texcoords.push((u, v));
             }
             "vn" => {
-                let x: f32 = tokens[1].parse().unwrap();
-                let y: f32 = tokens[2].parse().unwrap();
-                let z: f32 = tokens[3].parse().unwrap();
+                let x: f32 = parse_at(&tokens, 1, path, line_no)?;
+                let y: f32 = parse_at(&tokens, 2, path, line_no)?;
+                let z: f32 = parse_at(&tokens, 3, path, line_no)?;
                 normals.push(Vec3 { x, y, z });
             }
             "l" => {
-                let i1 = tokens[1].parse::<usize>().unwrap() - 1;
-                let i2 = tokens[2].parse::<usize>().unwrap() - 1;
-                edges.push((i1, i2));
+                if tokens.len() < 3 {
+                    return Err(LoadError::at(path, line_no, "Linie braucht zwei Punkte"));
+                }
+                // Polylinie, Einträge können "v/vt" sein
+                let mut points = Vec::new();
+                for s in &tokens[1..] {
+                    let v = s.split('/').next().unwrap_or("");
+                    points.push(parse_index(v, vertices.len(), path, line_no)?);
+                }
+                for pair in points.windows(2) {
+                    edges.push((pair[0], pair[1]));
+                }
             }
             "usemtl" => {
-                current_material = tokens[1].to_string();
+                current_material = tokens[1..].join(" ");
+            }
+            "mtllib" => {
+                for lib in &tokens[1..] {
+                    material_libs.push(dir.join(lib).to_string_lossy().to_string());
+                }
+            }
+            "o" => {
+                current_object = tokens[1..].join(" ");
+                current_group.clear();
+            }
+            "g" => {
+                current_group = tokens[1..].join(" ");
+            }
+            "s" => {
+                current_smoothing = match tokens.get(1) {
+                    Some(&"off") | None => 0,
+                    Some(_) => parse_at(&tokens, 1, path, line_no)?,
+                };
             }
             "f" => {
+                if tokens.len() < 4 {
+                    return Err(LoadError::at(path, line_no, "Face braucht mindestens drei Punkte"));
+                }
+
                 let mut face = Vec::new();
                 for s in &tokens[1..] {
                     let mut parts = s.split('/');
-                    let v_idx = parts.next().unwrap().parse::<usize>().unwrap() - 1;
-                    let t_idx = parts.next().and_then(|s| s.parse::<usize>().ok()).map(|i| i - 1);
-                    let n_idx = parts.next().and_then(|s| s.parse::<usize>().ok()).map(|i| i - 1);
+                    let v_idx = parse_index(parts.next().unwrap_or(""), vertices.len(), path, line_no)?;
+                    let t_idx = match parts.next() {
+                        Some(t) if !t.is_empty() => Some(parse_index(t, texcoords.len(), path, line_no)?),
+                        _ => None,
+                    };
+                    let n_idx = match parts.next() {
+                        Some(n) if !n.is_empty() => Some(parse_index(n, normals.len(), path, line_no)?),
+                        _ => None,
+                    };
                     face.push(FaceVertex {
                         vertex_index: v_idx,
                         texcoord_index: t_idx,
@@ -89,70 +251,289 @@ pub fn load_obj(path: &str) -> Model {
                     edges.push((a, b));
                 }
 
-                faces.push((face, current_material.clone()));
+                // Face seiner Gruppe zuordnen, gleiche Gruppen werden zusammengeführt
+                let face_idx = faces.len();
+                match groups.iter_mut().find(|g| g.object == current_object && g.name == current_group) {
+                    Some(group) => group.faces.push(face_idx),
+                    None => groups.push(Group {
+                        object: current_object.clone(),
+                        name: current_group.clone(),
+                        faces: vec![face_idx],
+                    }),
+                }
+
+                faces.push(Face {
+                    vertices: face,
+                    material: current_material.clone(),
+                    smoothing_group: current_smoothing,
+                });
             }
             _ => {}
         }
     }
 
-    Model {
+    let mut model = Model {
         vertices,
+        colors,
         texcoords,
         normals,
+        faces,
+        groups,
+        material_libs,
+        edges,
+        cull_mode: CullMode::Back,
+        front_face: Winding::CounterClockwise,
+        shading: ShadingMode::Flat,
+    };
+    generate_normals(&mut model);
+
+    Ok(model)
+}
+
+/// Geometrische Normale eines Polygons (Newell, auch für nicht-ebene Vielecke)
+fn face_normal(model: &Model, face: &Face) -> Vec3 {
+    let mut n = Vec3::ZERO;
+    for i in 0..face.vertices.len() {
+        let a = model.vertices[face.vertices[i].vertex_index];
+        let b = model.vertices[face.vertices[(i + 1) % face.vertices.len()].vertex_index];
+        n += Vec3::new(
+            (a.y - b.y) * (a.z + b.z),
+            (a.z - b.z) * (a.x + b.x),
+            (a.x - b.x) * (a.y + b.y),
+        );
+    }
+    n.normalize()
+}
+
+/// Fehlende Normalen ergänzen: in einer Glättungsgruppe gemittelt, sonst pro Face
+fn generate_normals(model: &mut Model) {
+    let mut smooth: HashMap<(usize, u32), Vec3> = HashMap::new();
+    let mut flat = Vec::new();
+
+    for face in &model.faces {
+        let n = face_normal(model, face);
+        flat.push(n);
+        if face.smoothing_group != 0 {
+            for fv in &face.vertices {
+                *smooth.entry((fv.vertex_index, face.smoothing_group)).or_default() += n;
+            }
+        }
+    }
+
+    let mut smooth_index: HashMap<(usize, u32), usize> = HashMap::new();
+
+    for (face_idx, face) in model.faces.iter_mut().enumerate() {
+        let mut flat_index = None;
+
+        for fv in face.vertices.iter_mut().filter(|fv| fv.normal_index.is_none()) {
+            if face.smoothing_group != 0 {
+                let key = (fv.vertex_index, face.smoothing_group);
+                let idx = *smooth_index.entry(key).or_insert_with(|| {
+                    model.normals.push(smooth[&key].normalize());
+                    model.normals.len() - 1
+                });
+                fv.normal_index = Some(idx);
+            } else {
+                let idx = *flat_index.get_or_insert_with(|| {
+                    model.normals.push(flat[face_idx]);
+                    model.normals.len() - 1
+                });
+                fv.normal_index = Some(idx);
+            }
+        }
+    }
+}
+
+/// Alle per `mtllib` eingebundenen Materialien eines Modells laden
+pub fn load_model_materials(model: &Model) -> Result<HashMap<String, Material>, LoadError> {
+    let mut materials = HashMap::new();
+    for lib in &model.material_libs {
+        materials.extend(load_mtl(lib)?);
+    }
+    Ok(materials)
+}
+
+/// Ersatz-Würfel (Kantenlänge 1) für Modelle, die nicht geladen werden konnten.
+/// Das Material ist leer, wird also in der Fehlerfarbe gezeichnet.
+pub fn placeholder_model() -> Model {
+    let vertices = vec![
+        Vec3::new(-0.5, -0.5, -0.5),
+        Vec3::new(0.5, -0.5, -0.5),
+        Vec3::new(0.5, 0.5, -0.5),
+        Vec3::new(-0.5, 0.5, -0.5),
+        Vec3::new(-0.5, -0.5, 0.5),
+        Vec3::new(0.5, -0.5, 0.5),
+        Vec3::new(0.5, 0.5, 0.5),
+        Vec3::new(-0.5, 0.5, 0.5),
+    ];
+    let normals = vec![
+        Vec3::new(0.0, 0.0, -1.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        Vec3::new(-1.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, -1.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+    ];
+    // gegen den Uhrzeigersinn von außen gesehen
+    let quads: [([usize; 4], usize); 6] = [
+        ([0, 3, 2, 1], 0),
+        ([4, 5, 6, 7], 1),
+        ([0, 4, 7, 3], 2),
+        ([1, 2, 6, 5], 3),
+        ([0, 1, 5, 4], 4),
+        ([3, 7, 6, 2], 5),
+    ];
+
+    let mut faces = Vec::new();
+    let mut edges = Vec::new();
+    for (quad, n) in quads {
+        for i in 0..4 {
+            edges.push((quad[i], quad[(i + 1) % 4]));
+        }
+        let face = quad
+            .iter()
+            .map(|&v| FaceVertex { vertex_index: v, texcoord_index: None, normal_index: Some(n) })
+            .collect();
+        faces.push(Face { vertices: face, material: String::new(), smoothing_group: 0 });
+    }
+
+    Model {
+        vertices,
+        colors: Vec::new(),
+        texcoords: Vec::new(),
+        normals,
+        groups: vec![Group { object: String::new(), name: String::new(), faces: (0..faces.len()).collect() }],
+        material_libs: Vec::new(),
         edges,
         faces,
+        cull_mode: CullMode::Back,
+        front_face: Winding::CounterClockwise,
+        shading: ShadingMode::Flat,
     }
 }
 
-pub fn load_mtl(path: &str) -> HashMap<String, (u8, u8, u8)> {
-    let file = File::open(path).expect("Konnte .mtl Datei nicht öffnen");
+/// Material aus der .mtl, Farben linear in 0..1
+pub struct Material {
+    /// Ka
+    pub ambient: Vec3,
+    /// Kd
+    pub diffuse: Vec3,
+    /// Ks
+    pub specular: Vec3,
+    /// Ns
+    pub shininess: f32,
+    /// Ke
+    pub emission: Vec3,
+    /// d (oder 1 - Tr)
+    pub opacity: f32,
+    /// 0 = nur Farbe, 1 = diffus, 2 = mit Glanzlicht
+    pub illum: u32,
+    /// map_Kd
+    pub texture: Option<Texture>,
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material {
+            ambient: Vec3::ONE,
+            diffuse: Vec3::ONE,
+            specular: Vec3::ZERO,
+            shininess: 1.0,
+            emission: Vec3::ZERO,
+            opacity: 1.0,
+            illum: 1,
+            texture: None,
+        }
+    }
+}
+
+fn parse_color(tokens: &[&str], path: &str, line_no: usize) -> Result<Vec3, LoadError> {
+    let r: f32 = parse_at(tokens, 1, path, line_no)?;
+    // "Kd 0.5" heißt grau
+    if tokens.len() == 2 {
+        return Ok(Vec3::splat(r));
+    }
+    let g: f32 = parse_at(tokens, 2, path, line_no)?;
+    let b: f32 = parse_at(tokens, 3, path, line_no)?;
+    Ok(Vec3::new(r, g, b))
+}
+
+/// Lädt alle Materialien einer MTL-Datei, nach Name
+pub fn load_mtl(path: &str) -> Result<HashMap<String, Material>, LoadError> {
+    let file = File::open(path).map_err(|e| LoadError::new(path, None, format!("Konnte .mtl Datei nicht öffnen: {}", e)))?;
     let reader = BufReader::new(file);
+    let dir = Path::new(path).parent().unwrap_or(Path::new(""));
 
-    let mut materials = HashMap::new();
+    let mut materials: HashMap<String, Material> = HashMap::new();
     let mut current_name = String::new();
 
-    for line in reader.lines() {
-        let line = line.unwrap();
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line_no = line_idx + 1;
+        let line = line.map_err(|e| LoadError::at(path, line_no, e.to_string()))?;
         let tokens: Vec<&str> = line.split_whitespace().collect();
         if tokens.is_empty() {
             continue;
         }
 
-        match tokens[0] {
-            "newmtl" => {
-                current_name = tokens[1].to_string();
-            }
-            "Kd" => {
-                let r: f32 = tokens[1].parse().unwrap_or(1.0);
-                let g: f32 = tokens[2].parse().unwrap_or(1.0);
-                let b: f32 = tokens[3].parse().unwrap_or(1.0);
+        if tokens[0] == "newmtl" {
+            current_name = parse_at::<String>(&tokens, 1, path, line_no)?;
+            materials.insert(current_name.clone(), Material::default());
+            continue;
+        }
 
-                let r_u8 = (r * 255.0) as u8;
-                let g_u8 = (g * 255.0) as u8;
-                let b_u8 = (b * 255.0) as u8;
+        let material = materials.entry(current_name.clone()).or_default();
 
-                materials.insert(current_name.clone(), (r_u8, g_u8, b_u8));
+        match tokens[0] {
+            "Ka" => material.ambient = parse_color(&tokens, path, line_no)?,
+            "Kd" => material.diffuse = parse_color(&tokens, path, line_no)?,
+            "Ks" => material.specular = parse_color(&tokens, path, line_no)?,
+            "Ke" => material.emission = parse_color(&tokens, path, line_no)?,
+            "Ns" => material.shininess = parse_at(&tokens, 1, path, line_no)?,
+            "d" => material.opacity = parse_at(&tokens, 1, path, line_no)?,
+            "Tr" => material.opacity = 1.0 - parse_at::<f32>(&tokens, 1, path, line_no)?,
+            "illum" => material.illum = parse_at(&tokens, 1, path, line_no)?,
+            "map_Kd" => {
+                if tokens.len() < 2 {
+                    return Err(LoadError::at(path, line_no, "map_Kd braucht einen Dateinamen"));
+                }
+                // Optionen wie "-s 1 1 1" überspringen, Dateiname steht am Ende
+                let file_name = tokens[tokens.len() - 1];
+                let texture_path = dir.join(file_name);
+                material.texture = Some(load_texture(&texture_path.to_string_lossy())?);
             }
             _ => {}
         }
     }
 
-    materials
+    Ok(materials)
 }
 
-fn draw_filled_polygon(
+/// Füllt ein Polygon scanlinienweise. `varyings` sind Attribute pro Eckpunkt,
+/// die perspektivisch korrekt (über 1/z) interpoliert an `color_fn` gehen.
+/// Bei `opacity` < 1 wird überblendet und der Z-Buffer nicht beschrieben.
+#[allow(clippy::too_many_arguments)]
+fn draw_filled_polygon<const N: usize>(
     points: &[(isize, isize)],
     depths: &[f32],
+    varyings: &[[f32; N]],
+    opacity: f32,
     buffer: &mut [u32],
     zbuffer: &mut [f32],
     width: usize,
     height: usize,
-    color_fn: impl Fn(f32, isize, isize) -> u32,
+    color_fn: impl Fn(f32, isize, isize, &[f32; N]) -> u32,
 ) {
     if points.len() < 3 {
         return; // Nicht genug Punkte für Fläche
     }
-    let avg_depth = depths.iter().copied().sum::<f32>() / depths.len() as f32;
+
+    // 1/z und a/z sind im Bildraum linear, z und a selbst nicht
+    let inv_depths: Vec<f32> = depths.iter().map(|z| 1.0 / z.max(0.0001)).collect();
+    let scaled: Vec<[f32; N]> = varyings
+        .iter()
+        .zip(&inv_depths)
+        .map(|(v, inv_z)| v.map(|a| a * inv_z))
+        .collect();
 
     // 1. Finde min/max Y
     let min_y = points.iter().map(|p| p.1).min().unwrap();
@@ -164,33 +545,52 @@ fn draw_filled_polygon(
             continue;
         }
 
-        let mut intersections = Vec::new();
+        let mut intersections: Vec<(f32, f32, [f32; N])> = Vec::new();
 
-        // 3. Finde alle Schnittpunkte der Scanline mit Polygonkanten
+        // 3. Finde alle Schnittpunkte der Scanline mit Polygonkanten (x, 1/z und a/z)
         for i in 0..points.len() {
+            let j = (i + 1) % points.len();
             let (x1, y1) = points[i];
-            let (x2, y2) = points[(i + 1) % points.len()];
+            let (x2, y2) = points[j];
 
             if (y1 <= y && y2 > y) || (y2 <= y && y1 > y) {
-                // Kante schneidet Scanline, berechne Schnittpunkt x
-                let x = x1 + (y - y1) * (x2 - x1) / (y2 - y1);
-                intersections.push(x);
+                // Kante schneidet Scanline, berechne Schnittpunkt
+                let t = (y - y1) as f32 / (y2 - y1) as f32;
+                let x = x1 as f32 + t * (x2 - x1) as f32;
+                let inv_z = inv_depths[i] + t * (inv_depths[j] - inv_depths[i]);
+                let attrs = lerp_attrs(&scaled[i], &scaled[j], t);
+                intersections.push((x, inv_z, attrs));
             }
         }
 
         // 4. Sortiere Schnittpunkte und fülle zwischen je zwei Punkten
-        intersections.sort();
+        intersections.sort_by(|a, b| a.0.total_cmp(&b.0));
 
         for pair in intersections.chunks(2) {
             if pair.len() == 2 {
-                let x_start = pair[0].max(0);
-                let x_end = pair[1].min(width as isize - 1);
+                let (xa, inv_za, attrs_a) = pair[0];
+                let (xb, inv_zb, attrs_b) = pair[1];
+                let span = xb - xa;
+
+                let x_start = (xa as isize).max(0);
+                let x_end = (xb as isize).min(width as isize - 1);
 
-                
                 for x in x_start..=x_end {
-                    if zbuffer[y as usize * width + x as usize] > avg_depth {
-                        zbuffer[y as usize * width + x as usize] = avg_depth;
-                        buffer[y as usize * width + x as usize] = color_fn(avg_depth, x, y);
+                    let t = if span > 0.0 { ((x as f32 - xa) / span).clamp(0.0, 1.0) } else { 0.0 };
+                    let inv_z = inv_za + t * (inv_zb - inv_za);
+                    let z = 1.0 / inv_z;
+
+                    let idx = y as usize * width + x as usize;
+                    if zbuffer[idx] > z {
+                        let attrs = lerp_attrs(&attrs_a, &attrs_b, t).map(|a| a * z);
+                        let color = color_fn(z, x, y, &attrs);
+
+                        if opacity < 1.0 {
+                            buffer[idx] = blend(color, buffer[idx], opacity);
+                        } else {
+                            zbuffer[idx] = z;
+                            buffer[idx] = color;
+                        }
                     }
                 }
             }
@@ -198,111 +598,191 @@ fn draw_filled_polygon(
     }
 }
 
-
-fn inverse_transform(v: Vec3, angle: (f32, f32, f32), pos: (f32, f32, f32)) -> Vec3 {
-    // Erst Translation rückgängig machen
-    let mut x = v.x - pos.0;
-    let mut y = v.y - pos.1;
-    let mut z = v.z - pos.2;
-
-    // Dann inverse Rotation (entgegengesetzt)
-    let (rx, ry, rz) = angle;
-    
-    // Umgekehrte Z-Rotation
-    let sin_z = (-rz).sin();
-    let cos_z = (-rz).cos();
-    let x2 = x * cos_z - y * sin_z;
-    let y2 = x * sin_z + y * cos_z;
-    x = x2;
-    y = y2;
-
-    // Umgekehrte Y-Rotation
-    let sin_y = (-ry).sin();
-    let cos_y = (-ry).cos();
-    let x1 = x * cos_y - z * sin_y;
-    let z1 = x * sin_y + z * cos_y;
-    x = x1;
-    z = z1;
-
-    // Umgekehrte X-Rotation
-    let sin_x = (-rx).sin();
-    let cos_x = (-rx).cos();
-    let y1 = y * cos_x - z * sin_x;
-    let z2 = y * sin_x + z * cos_x;
-    y = y1;
-    z = z2;
-
-    Vec3 { x, y, z }
+fn lerp_attrs<const N: usize>(a: &[f32; N], b: &[f32; N], t: f32) -> [f32; N] {
+    let mut out = [0.0; N];
+    for i in 0..N {
+        out[i] = a[i] + t * (b[i] - a[i]);
+    }
+    out
 }
 
+/// Zeichnet ein Modell mit `camera` in den Buffer. `transform.scale` vergrößert
+/// das Objekt selbst; das frühere `scale` hat die Projektion geteilt, also
+/// verkleinert (alte 15 = heute 1, die Hitboxen waren schon immer ungeskaliert).
+/// Bildschirm-y zeigt nach unten, Welt-y nach oben, wie bei den Partikeln.
+#[allow(clippy::too_many_arguments)]
 pub fn reader(
     model: &Model,
-    world_pos: (f32, f32, f32, f32, f32, f32), // Kamera: x, y, z, rot_x, rot_y, rot_z
-    object_pos: (f32, f32, f32),              // NEU: Position des Objekts im Raum
-    scale: f32,
+    camera: &Camera,
+    transform: &Transform, // Position, Rotation und Skalierung des Objekts
     buffer: &mut [u32],
     zbuffer: &mut [f32],
     width: usize,
     height: usize,
-    material_map: &HashMap<String, (u8, u8, u8)>,
+    material_map: &HashMap<String, Material>,
     light_dir: Vec3,
     strake: f32,
 ) {
-    let camera_rot = (world_pos.3, world_pos.4, world_pos.5);
-    let camera_pos = (world_pos.0, world_pos.1, world_pos.2);
-
-    for (face, mat_name) in &model.faces {
-        let mut poly_points = Vec::new();
-        let mut depths = Vec::new();
+    // 🌍 Objekt -> Welt -> Kamera -> Clip-Space
+    let model_matrix = transform.matrix();
+    let mvp = camera.projection() * camera.view() * model_matrix;
+    let normal_matrix = transform.normal_matrix();
+    let planes = frustum_planes();
 
-        for fv in face {
-            let mut vertex = model.vertices[fv.vertex_index];
+    // Pink als Fehlerfarbe
+    let missing = Material { diffuse: Vec3::new(1.0, 0.0, 1.0), ..Material::default() };
 
-            // 🧠 Objekt-Position anwenden
-            vertex.x += object_pos.0;
-            vertex.y += object_pos.1;
-            vertex.z += object_pos.2;
+    for face in &model.faces {
+        // 🎨 Material aus Materialmap
+        let material = material_map.get(&face.material).unwrap_or(&missing);
 
-            // 🌍 In Kamerakoordinaten transformieren
-            let v = inverse_transform(vertex, camera_rot, camera_pos);
+        let world: Vec<Vec3> = face
+            .vertices
+            .iter()
+            .map(|fv| model_matrix.transform_point(model.vertices[fv.vertex_index]))
+            .collect();
+
+        // 🔁 Normale des Faces aus seiner Ebene (Kreuzprodukt der Kanten in der Welt)
+        let plane = world.windows(2).skip(1).fold(Vec3::ZERO, |n, w| n + (w[0] - world[0]).cross(w[1] - world[0]));
+        let face_normal = if plane.length_squared() > 0.0 {
+            match model.front_face {
+                Winding::CounterClockwise => plane.normalize(),
+                Winding::Clockwise => -plane.normalize(),
+            }
+        } else {
+            // entartetes Face: erste Normale aus der Datei
+            face.vertices
+                .iter()
+                .find_map(|fv| fv.normal_index)
+                .map(|i| normal_matrix.transform_vector(model.normals[i]))
+                .unwrap_or(Vec3::new(0.0, 0.0, 1.0))
+        };
+        let center = world.iter().fold(Vec3::ZERO, |acc, p| acc + *p) / world.len() as f32;
+
+        let mut clip_vertices = Vec::new();
+
+        for (fv, world_pos) in face.vertices.iter().zip(&world) {
+            let v = model.vertices[fv.vertex_index];
+
+            // Normale pro Eckpunkt, sonst die des Faces
+            let normal = fv
+                .normal_index
+                .map(|i| normal_matrix.transform_vector(model.normals[i]))
+                .unwrap_or(face_normal);
+
+            // Gouraud: Licht pro Eckpunkt, Phong: Normale und Weltposition
+            let shade = match model.shading {
+                ShadingMode::Flat => [0.0; 6],
+                ShadingMode::Gouraud => {
+                    let view_dir = camera.position - *world_pos;
+                    let (d, sp) = light_terms(material, normal, view_dir, light_dir, strake);
+                    [d.x, d.y, d.z, sp.x, sp.y, sp.z]
+                }
+                ShadingMode::Phong => [normal.x, normal.y, normal.z, world_pos.x, world_pos.y, world_pos.z],
+            };
+            let (tu, tv) = fv.texcoord_index.map(|i| model.texcoords[i]).unwrap_or((0.0, 0.0));
+            let tint = model.colors.get(fv.vertex_index).copied().unwrap_or(Vec3::ONE);
+            let varying = [
+                shade[0], shade[1], shade[2], shade[3], shade[4], shade[5],
+                tu, tv,
+                tint.x, tint.y, tint.z,
+            ];
+
+            clip_vertices.push(ClipVertex { pos: mvp.transform_vec4([v.x, v.y, v.z, 1.0]), varying });
+        }
 
-            depths.push(v.z);
-            poly_points.push(project(v, width, height, scale));
+        // ✂️ An Near-Plane und Sichtvolumen abschneiden
+        let clipped = clip_polygon(&clip_vertices, &planes);
+        if clipped.len() < 3 {
+            continue;
         }
 
-        // 🎨 Basisfarbe aus Materialmap
-        let base_color = material_map
-            .get(mat_name)
-            .map(|(r, g, b)| rgb(*r, *g, *b))
-            .unwrap_or(rgb(255, 0, 255)); // Pink als Fehlerfarbe
+        let screen: Vec<(f32, f32)> = clipped.iter().map(|c| camera.to_screen(c.pos, width, height)).collect();
 
-        // 🔁 Normale des Faces (erste verfügbare)
-        let face_normal = face
-            .iter()
-            .find_map(|fv| fv.normal_index)
-            .map(|i| model.normals[i])
-            .unwrap_or(Vec3 { x: 0.0, y: 0.0, z: 1.0 });
+        // 🔄 Back-Face-Culling über die Umlaufrichtung im Bild
+        if !is_visible(&screen, model.cull_mode, model.front_face) {
+            continue;
+        }
 
-        // 🎨 Licht anwenden über Modul
-        let shaded_color = apply_light(base_color, face_normal, light_dir, strake);
+        let poly_points: Vec<(isize, isize)> = screen.iter().map(|&(x, y)| (x as isize, y as isize)).collect();
+        let depths: Vec<f32> = clipped.iter().map(|c| c.pos[3]).collect();
+        let varyings: Vec<[f32; 11]> = clipped.iter().map(|c| c.varying).collect();
 
-        draw_filled_polygon(&poly_points, &depths, buffer, zbuffer, width, height, |_z, _x, _y| shaded_color);
+        // 🎨 Licht anwenden über Modul
+        let flat_light = light_terms(material, face_normal, camera.position - center, light_dir, strake);
+
+        draw_filled_polygon(&poly_points, &depths, &varyings, material.opacity, buffer, zbuffer, width, height, |_z, _x, _y, a| {
+            // Texel an perspektivisch korrekter UV, sonst Kd; dazu Vertex-Farbe
+            let albedo = material
+                .texture
+                .as_ref()
+                .map(|t| color_to_vec(t.sample(a[6], a[7])).mul_elem(material.diffuse))
+                .unwrap_or(material.diffuse)
+                .mul_elem(Vec3::new(a[8], a[9], a[10]));
+
+            let color = match model.shading {
+                ShadingMode::Flat => compose(material, albedo, flat_light.0, flat_light.1),
+                ShadingMode::Gouraud => compose(material, albedo, Vec3::new(a[0], a[1], a[2]), Vec3::new(a[3], a[4], a[5])),
+                ShadingMode::Phong => {
+                    let normal = Vec3::new(a[0], a[1], a[2]);
+                    let view_dir = camera.position - Vec3::new(a[3], a[4], a[5]);
+                    apply_light(material, albedo, normal, view_dir, light_dir, strake)
+                }
+            };
+            vec_to_color(color)
+        });
     }
 }
 
-fn project(v: Vec3, width: usize, height: usize, scale: f32) -> (isize, isize) {
-    let aspect_ratio = width as f32 / height as f32;
+/// Farbe als (r, g, b) in 0..1 zum Rechnen
+fn color_to_vec(color: u32) -> Vec3 {
+    Vec3::new(
+        ((color >> 16) & 0xFF) as f32 / 255.0,
+        ((color >> 8) & 0xFF) as f32 / 255.0,
+        (color & 0xFF) as f32 / 255.0,
+    )
+}
 
-    if v.z <= 0.0001 {
-        return (2000, 2000); // hinter der Kamera oder zu nah
-    }
+fn vec_to_color(c: Vec3) -> u32 {
+    rgb(
+        (c.x.clamp(0.0, 1.0) * 255.0) as u8,
+        (c.y.clamp(0.0, 1.0) * 255.0) as u8,
+        (c.z.clamp(0.0, 1.0) * 255.0) as u8,
+    )
+}
+
+/// Halbtransparente Farbe über den Hintergrund legen
+fn blend(src: u32, dst: u32, opacity: f32) -> u32 {
+    vec_to_color(color_to_vec(dst).lerp(color_to_vec(src), opacity))
+}
 
-    let x = (v.x / (v.z * scale)) * width as f32 / 2.0 * (1.0 / aspect_ratio * 12.0) + width as f32 / 2.0;
-    let y = (v.y / (v.z * scale)) * height as f32 / 2.0 * (1.0 / aspect_ratio * 16.0) + height as f32 / 2.0;
+/// Doppelte vorzeichenbehaftete Fläche im Bildraum (y nach unten).
+/// Positiv heißt: in der Welt gegen den Uhrzeigersinn, also zur Kamera gedreht.
+fn signed_area(points: &[(f32, f32)]) -> f32 {
+    let mut area = 0.0;
+    for i in 0..points.len() {
+        let (x1, y1) = points[i];
+        let (x2, y2) = points[(i + 1) % points.len()];
+        area += x1 * y2 - x2 * y1;
+    }
+    area
+}
 
-    (x as isize, y as isize)
+fn is_visible(points: &[(f32, f32)], cull_mode: CullMode, front_face: Winding) -> bool {
+    let area = signed_area(points);
+    let front = match front_face {
+        Winding::CounterClockwise => area > 0.0,
+        Winding::Clockwise => area < 0.0,
+    };
+
+    match cull_mode {
+        CullMode::None => true,
+        CullMode::Back => front,
+        CullMode::Front => !front,
+    }
 }
 
+/// Farbe im Pixelformat des Framebuffers (0xFFRRGGBB, oberes Byte wird ignoriert)
 pub fn rgb(r: u8, g: u8, b: u8) -> u32 {
     (255u32 << 24) | ((r as u32) << 16) | ((g as u32) << 8) | (b as u32)
 }
@@ -0,0 +1,128 @@
+// rander/textur.rs
+
+use std::fs::File;
+use std::io::{BufReader, Read};
+use crate::rander::rander_model::rgb;
+use crate::rander::fehler::LoadError;
+
+/// Bild im gleichen Pixelformat wie der Framebuffer (0xFFRRGGBB, oberes Byte wird ignoriert)
+#[derive(Clone, Debug)]
+pub struct Texture {
+    /// Breite in Texeln
+    pub width: usize,
+    /// Höhe in Texeln
+    pub height: usize,
+    /// Texel zeilenweise von oben
+    pub pixels: Vec<u32>,
+}
+
+impl Texture {
+    /// Nächster Texel an (u, v), wiederholt außerhalb von 0..1. v = 0 ist unten (OBJ).
+    pub fn sample(&self, u: f32, v: f32) -> u32 {
+        let u = u - u.floor();
+        let v = v - v.floor();
+
+        let x = ((u * self.width as f32) as usize).min(self.width - 1);
+        let y = (((1.0 - v) * self.height as f32) as usize).min(self.height - 1);
+
+        self.pixels[y * self.width + x]
+    }
+}
+
+/// Lädt PNG oder PPM (P3/P6), je nach Dateiendung
+pub fn load_texture(path: &str) -> Result<Texture, LoadError> {
+    if path.to_lowercase().ends_with(".ppm") {
+        load_ppm(path)
+    } else {
+        load_png(path)
+    }
+}
+
+fn load_png(path: &str) -> Result<Texture, LoadError> {
+    let file = File::open(path).map_err(|e| LoadError::new(path, None, format!("Konnte Textur nicht öffnen: {}", e)))?;
+    let mut decoder = png::Decoder::new(BufReader::new(file));
+    // Paletten, 16 Bit usw. auf 8 Bit pro Kanal bringen
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let invalid = |e: png::DecodingError| LoadError::new(path, None, format!("Ungültige PNG-Datei: {}", e));
+    let mut reader = decoder.read_info().map_err(invalid)?;
+    let mut data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut data).map_err(invalid)?;
+
+    let channels = info.color_type.samples();
+    let pixels = data[..info.buffer_size()]
+        .chunks(channels)
+        .map(|p| match channels {
+            1 | 2 => rgb(p[0], p[0], p[0]),
+            _ => rgb(p[0], p[1], p[2]),
+        })
+        .collect();
+
+    Ok(Texture {
+        width: info.width as usize,
+        height: info.height as usize,
+        pixels,
+    })
+}
+
+fn load_ppm(path: &str) -> Result<Texture, LoadError> {
+    let mut data = Vec::new();
+    File::open(path)
+        .and_then(|mut f| f.read_to_end(&mut data))
+        .map_err(|e| LoadError::new(path, None, format!("Konnte Textur nicht öffnen: {}", e)))?;
+    let invalid = |reason: &str| LoadError::new(path, None, reason);
+
+    // Header: Magic, Breite, Höhe, Maximalwert – Kommentare mit '#'
+    let mut pos = 0;
+    let mut header = Vec::new();
+    while header.len() < 4 {
+        while pos < data.len() && data[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        if pos < data.len() && data[pos] == b'#' {
+            while pos < data.len() && data[pos] != b'\n' {
+                pos += 1;
+            }
+            continue;
+        }
+        let start = pos;
+        while pos < data.len() && !data[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        if start == pos {
+            return Err(invalid("PPM-Header unvollständig"));
+        }
+        header.push(String::from_utf8_lossy(&data[start..pos]).to_string());
+    }
+
+    let width: usize = header[1].parse().map_err(|_| invalid("Ungültige PPM-Breite"))?;
+    let height: usize = header[2].parse().map_err(|_| invalid("Ungültige PPM-Höhe"))?;
+    let max: f32 = header[3].parse().map_err(|_| invalid("Ungültiger PPM-Maximalwert"))?;
+
+    let samples: Vec<f32> = match header[0].as_str() {
+        "P6" => data.get(pos + 1..).unwrap_or(&[]).iter().map(|&b| b as f32).collect(),
+        "P3" => String::from_utf8_lossy(&data[pos..])
+            .split_whitespace()
+            .map(|t| t.parse::<f32>().map_err(|_| invalid("Ungültiger PPM-Wert")))
+            .collect::<Result<_, _>>()?,
+        _ => return Err(invalid("Nur P3 und P6 werden unterstützt")),
+    };
+
+    if width == 0 || height == 0 {
+        return Err(invalid("Leeres Bild"));
+    }
+    if samples.len() < width * height * 3 {
+        return Err(invalid("Zu wenige Pixel"));
+    }
+
+    let pixels = samples
+        .chunks(3)
+        .take(width * height)
+        .map(|p| {
+            let c = |v: f32| (v / max * 255.0).round() as u8;
+            rgb(c(p[0]), c(p[1]), c(p[2]))
+        })
+        .collect();
+
+    Ok(Texture { width, height, pixels })
+}
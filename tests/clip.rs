@@ -0,0 +1,47 @@
+// tests/clip.rs
+//
+// Polygone am Sichtvolumen abschneiden, Attribute werden mitgeschnitten
+
+use rander3d::rander::clip::{clip_polygon, frustum_planes, ClipPlane, ClipVertex};
+
+fn vertex(pos: [f32; 4], varying: [f32; 2]) -> ClipVertex<2> {
+    ClipVertex { pos, varying }
+}
+
+#[test]
+fn near_plane_cut_interpolates_attributes() {
+    // ein Eckpunkt hinter der Near-Plane (z < 0)
+    let tri = [
+        vertex([0.0, 0.0, 1.0, 2.0], [0.0, 10.0]),
+        vertex([1.0, 0.0, -1.0, 2.0], [1.0, 20.0]),
+        vertex([0.0, 1.0, 1.0, 2.0], [0.0, 30.0]),
+    ];
+    let clipped = clip_polygon(&tri, &[ClipPlane::Near]);
+    assert_eq!(clipped.len(), 4);
+
+    // Schnitt jeweils auf halber Kante: z = 0, Attribute in der Mitte
+    let cut: Vec<_> = clipped.iter().filter(|v| v.pos[2] == 0.0).collect();
+    assert_eq!(cut.len(), 2);
+    assert_eq!(cut[0].pos, [0.5, 0.0, 0.0, 2.0]);
+    assert_eq!(cut[0].varying, [0.5, 15.0]);
+    assert_eq!(cut[1].pos, [0.5, 0.5, 0.0, 2.0]);
+    assert_eq!(cut[1].varying, [0.5, 25.0]);
+}
+
+#[test]
+fn polygons_outside_vanish_and_inside_stay() {
+    let inside = [
+        vertex([0.0, 0.0, 0.5, 1.0], [1.0, 1.0]),
+        vertex([0.5, 0.0, 0.5, 1.0], [2.0, 2.0]),
+        vertex([0.0, 0.5, 0.5, 1.0], [3.0, 3.0]),
+    ];
+    let clipped = clip_polygon(&inside, &frustum_planes());
+    assert_eq!(clipped.len(), 3);
+    assert_eq!(clipped[2].varying, [3.0, 3.0]);
+
+    let behind = inside.map(|mut v| {
+        v.pos[2] = -1.0;
+        v
+    });
+    assert!(clip_polygon(&behind, &frustum_planes()).is_empty());
+}
@@ -0,0 +1,64 @@
+// tests/eingabe.rs
+//
+// Tastenbelegung aus Datei und Abfrage der Aktionen
+
+use std::fs;
+use minifb::Key;
+use rander3d::logik::eingabe::*;
+
+fn write_config(name: &str, text: &str) -> String {
+    let dir = "target/eingabe-test";
+    fs::create_dir_all(dir).unwrap();
+    let path = format!("{}/{}.ron", dir, name);
+    fs::write(&path, text).unwrap();
+    path
+}
+
+#[test]
+fn shipped_config_matches_defaults() {
+    let config = load_input_config("assets/input.ron").unwrap();
+    let defaults = InputConfig::default();
+    for action in Action::ALL {
+        assert_eq!(config.bindings[&action], defaults.bindings[&action], "{:?}", action);
+    }
+}
+
+#[test]
+fn rebinding_keeps_other_defaults() {
+    let path = write_config(
+        "rebind",
+        "(invert_mouse: true, bindings: { MoveForward: [\"Up\", \"K\"], LookUp: [] })",
+    );
+    let config = load_input_config(&path).unwrap();
+
+    assert_eq!(config.bindings[&Action::MoveForward], [Key::Up, Key::K]);
+    assert!(config.bindings[&Action::LookUp].is_empty());
+    assert_eq!(config.bindings[&Action::MoveBack], [Key::S]);
+    assert!(config.invert_mouse);
+    assert_eq!(config.mouse_sensitivity, InputConfig::default().mouse_sensitivity);
+}
+
+#[test]
+fn unknown_key_is_an_error() {
+    let path = write_config("unknown", "(bindings: { Jump: [\"Leertaste\"] })");
+    let err = load_input_config(&path).unwrap_err();
+    assert!(err.reason.contains("Leertaste"));
+}
+
+#[test]
+fn actions_follow_keys() {
+    let mut input = Input::new(InputConfig::default());
+
+    input.update(&[Key::W, Key::Tab]);
+    assert!(input.is_down(Action::MoveForward));
+    assert!(input.just_pressed(Action::ReleaseMouse));
+    assert_eq!(input.axis(Action::MoveBack, Action::MoveForward), 1.0);
+
+    input.update(&[Key::W, Key::S, Key::Tab]);
+    assert!(!input.just_pressed(Action::ReleaseMouse));
+    assert_eq!(input.axis(Action::MoveBack, Action::MoveForward), 0.0);
+
+    input.update(&[]);
+    assert!(!input.is_down(Action::MoveForward));
+    assert_eq!(key_from_name("LeftShift"), Some(Key::LeftShift));
+}
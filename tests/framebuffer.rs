@@ -0,0 +1,74 @@
+// tests/framebuffer.rs
+//
+// Bilder speichern und wieder einlesen: PPM, PNG und render_to_file
+
+use std::fs;
+use std::path::PathBuf;
+use rander3d::math::Vec3;
+use rander3d::rander::framebuffer::Framebuffer;
+use rander3d::rander::kamera::Camera;
+use rander3d::rander::offscreen::{render_to_file, Scene};
+use rander3d::rander::rander_model::rgb;
+use rander3d::rander::textur::load_texture;
+
+fn scratch_file(name: &str) -> String {
+    let dir = PathBuf::from("target/framebuffer-test");
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(name).to_string_lossy().to_string()
+}
+
+/// Nicht quadratisch und jeder Pixel anders, damit vertauschte Achsen auffallen
+fn pattern() -> Framebuffer {
+    let mut fb = Framebuffer::new(5, 3);
+    for y in 0..fb.height {
+        for x in 0..fb.width {
+            fb.buffer[y * fb.width + x] = rgb(x as u8 * 50, y as u8 * 100, 255 - x as u8 * 10);
+        }
+    }
+    fb
+}
+
+/// Nur RGB vergleichen, das obere Byte wird ignoriert
+fn assert_same_pixels(path: &str, fb: &Framebuffer) {
+    let texture = load_texture(path).unwrap();
+    assert_eq!((texture.width, texture.height), (fb.width, fb.height));
+    let rgb_only = |pixels: &[u32]| pixels.iter().map(|c| c & 0xFFFFFF).collect::<Vec<_>>();
+    assert_eq!(rgb_only(&texture.pixels), rgb_only(&fb.buffer), "{}", path);
+}
+
+#[test]
+fn saved_images_read_back_unchanged() {
+    let fb = pattern();
+
+    let ppm = scratch_file("muster.ppm");
+    fb.save_ppm(&ppm).unwrap();
+    assert_same_pixels(&ppm, &fb);
+
+    let png = scratch_file("muster.png");
+    fb.save_png(&png).unwrap();
+    assert_same_pixels(&png, &fb);
+
+    // save wählt das Format nach der Endung
+    let upper = scratch_file("muster_gross.PPM");
+    fb.save(&upper).unwrap();
+    assert!(fs::read(&upper).unwrap().starts_with(b"P6"));
+}
+
+#[test]
+fn render_to_file_writes_the_returned_frame() {
+    let scene = Scene {
+        objects: Vec::new(),
+        particles: Vec::new(),
+        light_dir: Vec3::UP,
+        strake: 0.5,
+        overlay: Some("FPS: 0".to_string()),
+    };
+    let camera = Camera::new(60.0, 2.0, 0.1, 100.0);
+
+    let path = scratch_file("szene.png");
+    let fb = render_to_file(&scene, &camera, 40, 20, &path).unwrap();
+    assert_same_pixels(&path, &fb);
+
+    // ohne beschreibbaren Ordner gibt es einen Fehler statt eines Bildes
+    assert!(render_to_file(&scene, &camera, 40, 20, "target/gibt-es-nicht/x/szene.png").is_err());
+}
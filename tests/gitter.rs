@@ -0,0 +1,122 @@
+// tests/gitter.rs
+//
+// Broad-Phase: Raster einsortieren, Regionen, Paare, Strahlen, Abgleich mit der Welt
+
+use rander3d::logik::gitter::SpatialHash;
+use rander3d::logik::hitbox::{Collider, AABB};
+use rander3d::logik::welt::*;
+use rander3d::math::{Transform, Vec3};
+
+fn cube(center: Vec3, half: f32) -> AABB {
+    AABB { min: center - Vec3::splat(half), max: center + Vec3::splat(half) }
+}
+
+#[test]
+fn region_queries_follow_updates() {
+    let mut grid = SpatialHash::new(4.0);
+    grid.insert(Entity(1), cube(Vec3::ZERO, 1.0));
+    grid.insert(Entity(2), cube(Vec3::new(10.0, 0.0, 0.0), 1.0));
+    grid.insert(Entity(3), cube(Vec3::new(-30.0, 0.0, 0.0), 1.0));
+    assert_eq!(grid.len(), 3);
+
+    assert_eq!(grid.query(&cube(Vec3::new(0.5, 0.0, 0.0), 1.0)), [Entity(1)]);
+    assert_eq!(grid.query(&AABB { min: Vec3::splat(-2.0), max: Vec3::new(12.0, 2.0, 2.0) }), [Entity(1), Entity(2)]);
+    // gleiche Zelle, aber Hüllen berühren sich nicht
+    assert!(grid.query(&cube(Vec3::new(2.5, 2.5, 2.5), 0.5)).is_empty());
+
+    // verschieben und entfernen
+    grid.insert(Entity(1), cube(Vec3::new(10.0, 0.0, 0.0), 1.0));
+    assert!(grid.query(&cube(Vec3::ZERO, 1.0)).is_empty());
+    assert_eq!(grid.query(&cube(Vec3::new(10.0, 0.0, 0.0), 0.1)), [Entity(1), Entity(2)]);
+    assert!(grid.remove(Entity(2)));
+    assert!(!grid.remove(Entity(2)));
+    assert_eq!(grid.len(), 2);
+
+    // sehr große Region geht über alle Einträge
+    assert_eq!(grid.query(&cube(Vec3::ZERO, 1e6)), [Entity(1), Entity(3)]);
+}
+
+#[test]
+fn candidate_pairs_and_huge_objects() {
+    let mut grid = SpatialHash::new(1.0);
+    grid.insert(Entity(0), cube(Vec3::ZERO, 0.6));
+    grid.insert(Entity(1), cube(Vec3::new(1.0, 0.0, 0.0), 0.6));
+    grid.insert(Entity(2), cube(Vec3::new(5.0, 0.0, 0.0), 0.6));
+    // Boden über 1000 x 1000 Zellen
+    grid.insert(Entity(3), AABB { min: Vec3::new(-500.0, -1.0, -500.0), max: Vec3::new(500.0, -0.5, 500.0) });
+
+    assert_eq!(
+        grid.pairs(),
+        [(Entity(0), Entity(1)), (Entity(0), Entity(3)), (Entity(1), Entity(3)), (Entity(2), Entity(3))]
+    );
+    assert_eq!(grid.query(&cube(Vec3::new(300.0, -1.0, 300.0), 0.1)), [Entity(3)]);
+}
+
+#[test]
+fn ray_walks_through_cells() {
+    let mut grid = SpatialHash::new(2.0);
+    grid.insert(Entity(0), cube(Vec3::new(5.0, 0.0, 0.0), 0.5));
+    grid.insert(Entity(1), cube(Vec3::new(5.0, 5.0, 0.0), 0.5));
+    grid.insert(Entity(2), cube(Vec3::new(-5.0, 0.0, 0.0), 0.5));
+    grid.insert(Entity(3), cube(Vec3::new(50.0, 0.0, 0.0), 0.5));
+
+    let dir = Vec3::new(1.0, 0.0, 0.0);
+    assert_eq!(grid.query_ray(Vec3::new(0.1, 0.1, 0.1), dir, 10.0), [Entity(0)]);
+    assert_eq!(grid.query_ray(Vec3::new(0.1, 0.1, 0.1), dir, 100.0), [Entity(0), Entity(3)]);
+    // schräg nach oben zur zweiten Kiste
+    let up = Vec3::new(1.0, 1.0, 0.0).normalize();
+    assert!(grid.query_ray(Vec3::new(0.1, 0.1, 0.1), up, 10.0).contains(&Entity(1)));
+}
+
+#[test]
+fn endless_rays_only_walk_occupied_cells() {
+    let mut grid = SpatialHash::new(1.0);
+    let dir = Vec3::new(1.0, 0.0, 0.0);
+    assert!(grid.query_ray(Vec3::ZERO, dir, f32::INFINITY).is_empty());
+
+    grid.insert(Entity(0), cube(Vec3::new(3.0, 0.5, 0.5), 0.4));
+    // von weit außerhalb und ohne Grenze: nur der belegte Bereich wird abgelaufen
+    assert_eq!(grid.query_ray(Vec3::new(-1e7, 0.5, 0.5), dir, f32::INFINITY), [Entity(0)]);
+    assert!(grid.query_ray(Vec3::new(-1e7, 5.0, 0.5), dir, f32::INFINITY).is_empty());
+    // weggedreht oder zu kurz
+    assert!(grid.query_ray(Vec3::new(0.0, 0.5, 0.5), -dir, f32::INFINITY).is_empty());
+    assert!(grid.query_ray(Vec3::new(0.0, 0.5, 0.5), dir, 2.0).is_empty());
+
+    grid.remove(Entity(0));
+    assert!(grid.query_ray(Vec3::new(-1e7, 0.5, 0.5), dir, f32::INFINITY).is_empty());
+}
+
+#[test]
+fn world_stays_indexed() {
+    let mut world = World::new(Vec3::UP);
+    let boxes: Vec<Entity> = (0..2000)
+        .map(|i| {
+            let e = world.spawn("kiste");
+            let (x, z) = ((i % 50) as f32 * 3.0, (i / 50) as f32 * 3.0);
+            world.transforms.insert(e, Transform::new(Vec3::new(x, 0.0, z)));
+            world.colliders.insert(e, Collider::Box(cube(Vec3::ZERO, 0.5)));
+            e
+        })
+        .collect();
+    world.colliders.insert(world.player, Collider::Sphere { center: Vec3::ZERO, radius: 1.0 });
+    world.set_position(world.player, Vec3::new(3.0, 0.0, 1.5));
+    broadphase_system(&mut world);
+    assert_eq!(world.broadphase.len(), 2001);
+
+    // zwischen boxes[1] und boxes[51], kein Paar sonst
+    assert_eq!(collision_system(&world, world.player), [boxes[1], boxes[51]]);
+    assert_eq!(collision_pairs(&world), [(world.player, boxes[1]), (world.player, boxes[51])]);
+
+    // Bewegung und Löschen kommen nach dem nächsten Abgleich an
+    world.set_position(boxes[1], Vec3::new(-10.0, 0.0, -10.0));
+    world.despawn(boxes[51]);
+    broadphase_system(&mut world);
+    assert!(collision_system(&world, world.player).is_empty());
+    assert_eq!(world.broadphase.len(), 2000);
+
+    // Form geändert: herausnehmen, dann neu einsortiert
+    world.colliders.insert(world.player, Collider::Sphere { center: Vec3::ZERO, radius: 3.0 });
+    world.broadphase.remove(world.player);
+    broadphase_system(&mut world);
+    assert_eq!(collision_system(&world, world.player), [boxes[0], boxes[2], boxes[50], boxes[52]]);
+}
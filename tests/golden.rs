@@ -0,0 +1,186 @@
+// tests/golden.rs
+//
+// Golden-Image-Tests: Szenen aus festen Kamerapositionen rendern und mit
+// Referenzbildern in tests/golden/ vergleichen. Bei Abweichung landet ein
+// Diff-Bild in target/golden-diff/. Die Modelle liegen in tests/golden/fixtures/,
+// fehlt ein Modell oder eine Referenz, schlägt der Test fehl.
+//
+// Referenzen neu erzeugen: GOLDEN_UPDATE=1 cargo test golden
+
+use std::collections::HashMap;
+use std::fs;
+use rander3d::math::{Transform, Vec3};
+use rander3d::rander::framebuffer::Framebuffer;
+use rander3d::rander::kamera::Camera;
+use rander3d::rander::offscreen::{render_scene, Scene, SceneObject};
+use rander3d::rander::rander_model::{load_model_materials, load_obj, placeholder_model, Material, Model, ShadingMode};
+use rander3d::rander::textur::load_texture;
+
+const WIDTH: usize = 160;
+const HEIGHT: usize = 90;
+
+const GOLDEN_DIR: &str = "tests/golden";
+const FIXTURE_DIR: &str = "tests/golden/fixtures";
+const DIFF_DIR: &str = "target/golden-diff";
+
+/// Erlaubte Abweichung pro Farbkanal
+const TOLERANCE: u8 = 4;
+/// Anteil Pixel, die darüber liegen dürfen (Kanten können um einen Pixel springen)
+const MAX_BAD_RATIO: f32 = 0.002;
+
+/// Feste Blickrichtungen, relativ zur Größe des Modells
+const POSES: [(&str, Vec3); 3] = [
+    ("front", Vec3::new(0.0, 0.3, -1.0)),
+    ("corner", Vec3::new(1.0, 0.8, -1.0)),
+    ("back", Vec3::new(-0.6, 0.2, 1.0)),
+];
+
+fn camera_for(model: &Model, dir: Vec3) -> Camera {
+    let mut min = model.vertices[0];
+    let mut max = model.vertices[0];
+    for v in &model.vertices {
+        min = min.min(*v);
+        max = max.max(*v);
+    }
+    let center = (min + max) * 0.5;
+    let radius = ((max - min) * 0.5).length().max(0.001);
+
+    let mut camera = Camera::new(60.0, WIDTH as f32 / HEIGHT as f32, 0.1, 1000.0);
+    camera.position = center + dir.normalize() * radius * 2.5;
+    camera.look_at(center);
+    camera
+}
+
+fn render(model: &Model, materials: &HashMap<String, Material>, camera: &Camera) -> Framebuffer {
+    let scene = Scene {
+        objects: vec![SceneObject { model, transform: Transform::new(Vec3::ZERO), materials }],
+        particles: Vec::new(),
+        light_dir: Vec3::new(0.3, 1.0, -0.5),
+        strake: 0.3,
+        overlay: None,
+    };
+    let mut fb = Framebuffer::new(WIDTH, HEIGHT);
+    render_scene(&scene, camera, &mut fb);
+    fb
+}
+
+/// Vergleicht mit der Referenz, gibt bei Abweichung eine Beschreibung zurück
+fn check(name: &str, fb: &Framebuffer) -> Result<(), String> {
+    let reference_path = format!("{}/{}.png", GOLDEN_DIR, name);
+
+    if std::env::var("GOLDEN_UPDATE").is_ok() {
+        fs::create_dir_all(GOLDEN_DIR).map_err(|e| e.to_string())?;
+        return fb.save_png(&reference_path).map_err(|e| e.to_string());
+    }
+
+    let reference = load_texture(&reference_path)
+        .map_err(|e| format!("{} (mit GOLDEN_UPDATE=1 erzeugen)", e))?;
+    if reference.width != fb.width || reference.height != fb.height {
+        return Err(format!("{}: Größe {}x{} statt {}x{}", name, fb.width, fb.height, reference.width, reference.height));
+    }
+
+    let mut diff = Framebuffer::new(fb.width, fb.height);
+    let mut bad = 0;
+    for (i, (&a, &b)) in fb.buffer.iter().zip(&reference.pixels).enumerate() {
+        let max_delta = [16, 8, 0]
+            .iter()
+            .map(|shift| ((a >> shift) as u8).abs_diff((b >> shift) as u8))
+            .max()
+            .unwrap();
+
+        if max_delta > TOLERANCE {
+            bad += 1;
+            diff.buffer[i] = 0xFF0000;
+        } else {
+            // Referenz abgedunkelt als Hintergrund
+            diff.buffer[i] = (b >> 2) & 0x3F3F3F;
+        }
+    }
+
+    let allowed = (fb.buffer.len() as f32 * MAX_BAD_RATIO) as usize;
+    if bad > allowed {
+        fs::create_dir_all(DIFF_DIR).map_err(|e| e.to_string())?;
+        let diff_path = format!("{}/{}.png", DIFF_DIR, name);
+        let actual_path = format!("{}/{}.actual.png", DIFF_DIR, name);
+        diff.save_png(&diff_path).map_err(|e| e.to_string())?;
+        fb.save_png(&actual_path).map_err(|e| e.to_string())?;
+        return Err(format!("{}: {} Pixel weichen ab (erlaubt {}), siehe {}", name, bad, allowed, diff_path));
+    }
+
+    Ok(())
+}
+
+fn check_all_poses(name: &str, model: &Model, materials: &HashMap<String, Material>) -> Vec<String> {
+    POSES
+        .iter()
+        .filter_map(|(pose, dir)| {
+            let fb = render(model, materials, &camera_for(model, *dir));
+            check(&format!("{}_{}", name, pose), &fb).err()
+        })
+        .collect()
+}
+
+#[test]
+fn golden_placeholder_cube() {
+    let model = placeholder_model();
+    let mut materials = HashMap::new();
+    materials.insert(String::new(), Material { diffuse: Vec3::new(1.0, 0.5, 0.2), ..Material::default() });
+
+    let failures = check_all_poses("placeholder", &model, &materials);
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Ein Fixture-Modell aus fester Kamera
+struct Case {
+    name: &'static str,
+    obj: &'static str,
+    shading: ShadingMode,
+    eye: Vec3,
+    target: Vec3,
+}
+
+const CASES: [Case; 5] = [
+    // Textur über perspektivisch korrekte UVs
+    Case { name: "textured", obj: "textured.obj", shading: ShadingMode::Flat, eye: Vec3::new(1.2, 1.0, -1.6), target: Vec3::ZERO },
+    // Licht pro Eckpunkt bzw. pro Pixel mit Glanzlicht
+    Case { name: "gouraud", obj: "smooth.obj", shading: ShadingMode::Gouraud, eye: Vec3::new(0.8, 0.6, -3.0), target: Vec3::ZERO },
+    Case { name: "phong", obj: "smooth.obj", shading: ShadingMode::Phong, eye: Vec3::new(0.8, 0.6, -3.0), target: Vec3::ZERO },
+    // Boden reicht hinter die Kamera, wird an der Near-Plane geschnitten
+    Case { name: "near_clip", obj: "floor.obj", shading: ShadingMode::Flat, eye: Vec3::new(0.0, 0.3, -1.0), target: Vec3::new(0.0, 0.0, 3.0) },
+    // durch die offene Seite sind nur Rückseiten zu sehen, die fehlen
+    Case { name: "culled", obj: "openbox.obj", shading: ShadingMode::Flat, eye: Vec3::new(1.2, 1.0, -2.2), target: Vec3::ZERO },
+];
+
+#[test]
+fn golden_fixtures() {
+    let mut failures = Vec::new();
+    for case in &CASES {
+        // fehlende Dateien sind Fehler, nicht übersprungen
+        let path = format!("{}/{}", FIXTURE_DIR, case.obj);
+        let mut model = match load_obj(&path) {
+            Ok(model) => model,
+            Err(e) => {
+                failures.push(e.to_string());
+                continue;
+            }
+        };
+        let materials = match load_model_materials(&model) {
+            Ok(materials) => materials,
+            Err(e) => {
+                failures.push(e.to_string());
+                continue;
+            }
+        };
+        model.shading = case.shading;
+
+        let mut camera = Camera::new(60.0, WIDTH as f32 / HEIGHT as f32, 0.1, 1000.0);
+        camera.position = case.eye;
+        camera.look_at(case.target);
+
+        if let Err(e) = check(case.name, &render(&model, &materials, &camera)) {
+            failures.push(e);
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
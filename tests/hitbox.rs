@@ -0,0 +1,162 @@
+// tests/hitbox.rs
+//
+// Swept-AABB: Zeitpunkt und Normale des Kontakts, Gleiten an Wänden;
+// Hitboxen mit Transform: skaliert, gedreht (OBB); Kugel, Kapsel, Dreiecke
+
+use std::f32::consts::FRAC_PI_4;
+use rander3d::logik::hitbox::{check_collision, is_axis_aligned, resolve_movement, sweep_aabb, Collider, AABB, SKIN};
+use rander3d::math::{Quat, Transform, Vec3};
+use rander3d::rander::rander_model::placeholder_model;
+
+fn unit() -> AABB {
+    AABB { min: Vec3::splat(-0.5), max: Vec3::splat(0.5) }
+}
+
+#[test]
+fn sweep_finds_time_and_normal() {
+    // Lücke von 1 bis zur Box, Bewegung 4 → Kontakt bei 1/4
+    let hit = sweep_aabb(&unit(), &unit(), Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0)).unwrap();
+    assert!((hit.time - 0.25).abs() < 1e-6);
+    assert_eq!(hit.normal, Vec3::new(-1.0, 0.0, 0.0));
+
+    // von oben auf die Box
+    let hit = sweep_aabb(&unit(), &unit(), Vec3::new(0.0, 3.0, 0.0), Vec3::ZERO, Vec3::new(0.0, -4.0, 0.0)).unwrap();
+    assert!((hit.time - 0.5).abs() < 1e-6);
+    assert_eq!(hit.normal, Vec3::new(0.0, 1.0, 0.0));
+
+    // zu kurz oder daneben
+    assert!(sweep_aabb(&unit(), &unit(), Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.5, 0.0, 0.0)).is_none());
+    assert!(sweep_aabb(&unit(), &unit(), Vec3::new(0.0, 2.0, 0.0), Vec3::new(2.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0)).is_none());
+}
+
+#[test]
+fn slides_along_wall() {
+    // Wand bei x = 1..2, schräg dagegen laufen
+    let wall = (AABB { min: Vec3::new(1.0, -5.0, -50.0), max: Vec3::new(2.0, 5.0, 50.0) }, Vec3::ZERO);
+    let (pos, hits) = resolve_movement(&unit(), Vec3::ZERO, Vec3::new(2.0, 0.0, 3.0), &[wall]);
+
+    assert!((pos.x - (0.5 - SKIN)).abs() < 1e-4);
+    assert!((pos.z - 3.0).abs() < 1e-6);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].0, 0);
+    assert_eq!(hits[0].1.normal, Vec3::new(-1.0, 0.0, 0.0));
+}
+
+#[test]
+fn walks_out_of_overlap() {
+    let other = (unit(), Vec3::new(0.5, 0.0, 0.0));
+    let (pos, hits) = resolve_movement(&unit(), Vec3::ZERO, Vec3::new(-2.0, 0.0, 0.0), &[other]);
+    assert_eq!(pos, Vec3::new(-2.0, 0.0, 0.0));
+    assert!(hits.is_empty());
+}
+
+#[test]
+fn hitbox_follows_scale_and_rotation() {
+    let t = Transform { position: Vec3::new(10.0, 0.0, 0.0), rotation: Quat::IDENTITY, scale: Vec3::splat(15.0) };
+    let world = unit().transformed(&t);
+    assert_eq!((world.min, world.max), (Vec3::new(2.5, -7.5, -7.5), Vec3::new(17.5, 7.5, 7.5)));
+
+    // 45° um y: Hülle wird breiter
+    let t = Transform { rotation: Quat::from_euler(0.0, FRAC_PI_4, 0.0), ..Transform::default() };
+    let world = unit().transformed(&t);
+    assert!((world.max.x - 0.5 * 2f32.sqrt()).abs() < 1e-5);
+    assert!((world.max.y - 0.5).abs() < 1e-5);
+
+    assert!(is_axis_aligned(Quat::from_euler(0.0, std::f32::consts::FRAC_PI_2, 0.0)));
+    assert!(!is_axis_aligned(t.rotation));
+}
+
+#[test]
+fn rotated_boxes_use_sat() {
+    let turned = Transform { position: Vec3::new(1.3, 0.0, 0.0), rotation: Quat::from_euler(0.0, FRAC_PI_4, 0.0), scale: Vec3::ONE };
+    // Hüllen überlappen, die schräge Kante der gedrehten Box ist aber noch weg
+    let close = Transform::new(Vec3::new(0.1, 0.0, 0.6));
+    assert!(unit().transformed(&turned).min.x < close.position.x + 0.5);
+    assert!(!check_collision(&unit(), &close, &unit(), &turned));
+
+    let closer = Transform::new(Vec3::new(0.3, 0.0, 0.6));
+    assert!(check_collision(&unit(), &closer, &unit(), &turned));
+
+    // achsenparallel und skaliert
+    let big = Transform { scale: Vec3::splat(4.0), ..Transform::new(Vec3::new(2.0, 0.0, 0.0)) };
+    assert!(check_collision(&unit(), &Transform::default(), &unit(), &big));
+    assert!(!check_collision(&unit(), &Transform::default(), &unit(), &Transform::new(Vec3::new(2.0, 0.0, 0.0))));
+}
+
+fn at(x: f32, y: f32, z: f32) -> Transform {
+    Transform::new(Vec3::new(x, y, z))
+}
+
+fn ball(radius: f32) -> Collider {
+    Collider::Sphere { center: Vec3::ZERO, radius }
+}
+
+fn stick() -> Collider {
+    Collider::Capsule { a: Vec3::new(0.0, -1.0, 0.0), b: Vec3::new(0.0, 1.0, 0.0), radius: 0.25 }
+}
+
+// ein Dreieck in der Ebene y = 0
+fn floor_tri() -> Collider {
+    Collider::Mesh(vec![[Vec3::new(-2.0, 0.0, -2.0), Vec3::new(2.0, 0.0, -2.0), Vec3::new(0.0, 0.0, 2.0)]])
+}
+
+#[test]
+fn spheres_and_capsules() {
+    assert!(ball(1.0).intersects(&at(0.0, 0.0, 0.0), &ball(1.0), &at(2.0, 0.0, 0.0)));
+    assert!(!ball(1.0).intersects(&at(0.0, 0.0, 0.0), &ball(1.0), &at(2.1, 0.0, 0.0)));
+    // Skalierung vergrößert den Radius
+    let big = Transform { scale: Vec3::splat(2.0), ..at(2.5, 0.0, 0.0) };
+    assert!(ball(1.0).intersects(&at(0.0, 0.0, 0.0), &ball(1.0), &big));
+
+    // Kapsel neben der Kugel, auf Höhe der Strecke
+    assert!(stick().intersects(&at(0.0, 0.0, 0.0), &ball(0.5), &at(0.7, 0.8, 0.0)));
+    assert!(!stick().intersects(&at(0.0, 0.0, 0.0), &ball(0.5), &at(0.8, 0.8, 0.0)));
+    // zwei gekreuzte Kapseln
+    let lying = Transform { rotation: Quat::from_euler(0.0, 0.0, std::f32::consts::FRAC_PI_2), ..at(0.45, 0.0, 0.0) };
+    assert!(stick().intersects(&at(0.0, 0.0, 0.0), &stick(), &lying));
+    assert!(!stick().intersects(&at(0.0, 0.0, 0.0), &stick(), &at(0.6, 0.0, 0.0)));
+}
+
+#[test]
+fn round_shapes_against_boxes() {
+    // Kugel an der Ecke: Hüllen überlappen, die Form nicht
+    let b = Collider::Box(unit());
+    assert!(!ball(0.5).intersects(&at(0.9, 0.9, 0.9), &b, &at(0.0, 0.0, 0.0)));
+    assert!(ball(0.5).intersects(&at(0.9, 0.0, 0.0), &b, &at(0.0, 0.0, 0.0)));
+
+    // Kapsel quer über einer gedrehten Box
+    let turned = Transform { rotation: Quat::from_euler(0.0, FRAC_PI_4, 0.0), ..at(0.0, 0.0, 0.0) };
+    assert!(stick().intersects(&at(0.8, 0.0, 0.0), &b, &turned));
+    assert!(!stick().intersects(&at(1.0, 0.0, 0.0), &b, &turned));
+}
+
+#[test]
+fn triangle_meshes_are_exact() {
+    let floor = floor_tri();
+    let origin = at(0.0, 0.0, 0.0);
+
+    assert!(ball(0.5).intersects(&at(0.0, 0.4, 0.0), &floor, &origin));
+    assert!(!ball(0.5).intersects(&at(0.0, 0.6, 0.0), &floor, &origin));
+    // neben der schrägen Kante, aber in der Hülle
+    assert!(!ball(0.3).intersects(&at(1.8, 0.0, 1.5), &floor, &origin));
+
+    assert!(stick().intersects(&at(0.0, 1.2, 0.0), &floor, &origin));
+    assert!(Collider::Box(unit()).intersects(&at(0.0, 0.5, 0.0), &floor, &origin));
+    assert!(!Collider::Box(unit()).intersects(&at(0.0, 0.6, 0.0), &floor, &origin));
+
+    // senkrechtes Dreieck durch das liegende, und daneben
+    let wall = Collider::Mesh(vec![[Vec3::new(0.0, -1.0, 0.0), Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0)]]);
+    assert!(floor.intersects(&origin, &wall, &origin));
+    assert!(!floor.intersects(&origin, &wall, &at(0.0, 0.0, 2.5)));
+}
+
+#[test]
+fn mesh_collider_from_model() {
+    let model = placeholder_model();
+    let Some(Collider::Mesh(tris)) = Collider::from_model(&model) else {
+        panic!("Würfel ohne Dreiecke");
+    };
+    // 6 Vierecke, je 2 Dreiecke
+    assert_eq!(tris.len(), 12);
+    assert_eq!(Collider::Mesh(tris).bounds(&at(0.0, 0.0, 0.0)), unit());
+}
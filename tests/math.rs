@@ -0,0 +1,92 @@
+// tests/math.rs
+//
+// Vektoren, Quaternionen und Matrizen: linkshändig, y oben, Kamera schaut nach +z
+
+use std::f32::consts::FRAC_PI_2;
+use rander3d::math::{Mat4, Quat, Vec3};
+
+fn close(a: Vec3, b: Vec3) -> bool {
+    (a - b).length() < 1e-5
+}
+
+fn close_mat(a: Mat4, b: Mat4) -> bool {
+    a.m.iter().flatten().zip(b.m.iter().flatten()).all(|(x, y)| (x - y).abs() < 1e-5)
+}
+
+#[test]
+fn inverse_undoes_the_matrix() {
+    let rotation = Quat::from_euler(0.3, -1.2, 0.7);
+    let m = Mat4::trs(Vec3::new(1.0, -2.0, 5.0), rotation, Vec3::new(2.0, 0.5, 3.0));
+    let inv = m.inverse().unwrap();
+    assert!(close_mat(inv * m, Mat4::IDENTITY), "{:?}", inv * m);
+    assert!(close_mat(m * inv, Mat4::IDENTITY), "{:?}", m * inv);
+
+    // Pivot auf der Diagonale ist 0, braucht Zeilentausch
+    let swapped = Mat4 {
+        m: [
+            [0.0, 1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 2.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ],
+    };
+    assert!(close_mat(swapped.inverse().unwrap() * swapped, Mat4::IDENTITY));
+
+    // flachgedrückt oder mit doppelter Zeile gibt es keine Inverse
+    assert_eq!(Mat4::scale(Vec3::new(1.0, 0.0, 1.0)).inverse(), None);
+    let mut twice = Mat4::IDENTITY;
+    twice.m[1] = twice.m[0];
+    assert_eq!(twice.inverse(), None);
+}
+
+#[test]
+fn yaw_turns_forward_to_the_right() {
+    let forward = Vec3::new(0.0, 0.0, 1.0);
+    let right = Vec3::new(1.0, 0.0, 0.0);
+    let yaw = Quat::from_euler(0.0, FRAC_PI_2, 0.0);
+    assert!(close(yaw.rotate(forward), right), "{:?}", yaw.rotate(forward));
+
+    // Pitch nach vorne (positiv) schaut nach unten
+    let pitch = Quat::from_euler(FRAC_PI_2, 0.0, 0.0);
+    assert!(close(pitch.rotate(forward), -Vec3::UP));
+
+    // erst Roll, dann Yaw: +x wird zu +y und bleibt dort
+    let both = Quat::from_euler(0.0, FRAC_PI_2, FRAC_PI_2);
+    assert!(close(both.rotate(right), Vec3::UP), "{:?}", both.rotate(right));
+
+    // Matrix dreht genauso wie das Quaternion
+    let q = Quat::from_euler(0.4, 1.1, -0.6);
+    let v = Vec3::new(0.3, -2.0, 1.5);
+    assert!(close(q.to_mat4().transform_vector(v), q.rotate(v)));
+}
+
+#[test]
+fn look_at_puts_the_target_in_front() {
+    let eye = Vec3::new(3.0, 1.0, 0.0);
+    let view = Mat4::look_at(eye, Vec3::new(0.0, 1.0, 0.0), Vec3::UP);
+
+    assert!(close(view.transform_point(eye), Vec3::ZERO));
+    assert!(close(view.transform_point(Vec3::new(0.0, 1.0, 0.0)), Vec3::new(0.0, 0.0, 3.0)));
+    // Blick nach -x: rechts ist +z, oben bleibt oben
+    assert!(close(view.transform_point(Vec3::new(3.0, 1.0, 1.0)), Vec3::new(1.0, 0.0, 0.0)));
+    assert!(close(view.transform_point(Vec3::new(3.0, 2.0, 0.0)), Vec3::UP));
+}
+
+#[test]
+fn perspective_maps_near_to_zero_and_far_to_w() {
+    let (near, far) = (0.5, 100.0);
+    let proj = Mat4::perspective(90.0, 2.0, near, far);
+
+    let [_, _, z, w] = proj.transform_vec4([0.0, 0.0, near, 1.0]);
+    assert!(z.abs() < 1e-5);
+    assert!((w - near).abs() < 1e-5);
+
+    let [_, _, z, w] = proj.transform_vec4([0.0, 0.0, far, 1.0]);
+    assert!((z - w).abs() < 1e-3);
+    assert!((w - far).abs() < 1e-5);
+
+    // Oberkante des Sichtfelds landet bei y = w, die rechte Kante mit aspect 2 bei x = w
+    let [x, y, _, w] = proj.transform_vec4([20.0, 10.0, 10.0, 1.0]);
+    assert!((y - w).abs() < 1e-5);
+    assert!((x - w).abs() < 1e-5);
+}
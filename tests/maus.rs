@@ -0,0 +1,42 @@
+// tests/maus.rs
+//
+// Umsehen mit der Maus und Begrenzung des Pitch
+
+use std::f32::consts::PI;
+use rander3d::logik::maus::MouseLook;
+use rander3d::rander::kamera::{Camera, MAX_PITCH};
+
+#[test]
+fn pitch_never_flips_over() {
+    let mut camera = Camera::new(60.0, 1.0, 0.1, 100.0);
+    camera.turn(0.0, 10.0);
+    assert_eq!(camera.pitch, MAX_PITCH);
+    camera.turn(0.0, -20.0);
+    assert_eq!(camera.pitch, -MAX_PITCH);
+
+    camera.turn(3.0 * PI, 0.0);
+    assert!(camera.yaw.abs() <= PI);
+}
+
+#[test]
+fn mouse_moves_camera_only_when_captured() {
+    let mut camera = Camera::new(60.0, 1.0, 0.1, 100.0);
+    let mut mouse = MouseLook::new(0.01, false);
+
+    assert_eq!(mouse.delta(Some((10.0, 10.0))), (0.0, 0.0));
+
+    mouse.capture(true);
+    // erste Position nach dem Fangen ist kein Sprung
+    assert_eq!(mouse.delta(Some((50.0, 50.0))), (0.0, 0.0));
+    let d = mouse.delta(Some((60.0, 45.0)));
+    assert_eq!(d, (10.0, -5.0));
+
+    mouse.apply(&mut camera, d);
+    assert!((camera.yaw - 0.1).abs() < 1e-6);
+    assert!((camera.pitch + 0.05).abs() < 1e-6);
+
+    mouse.invert_y = true;
+    mouse.apply(&mut camera, (0.0, -5.0));
+    // Maus hoch = nach unten schauen
+    assert!(camera.pitch.abs() < 1e-6);
+}
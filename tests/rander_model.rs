@@ -0,0 +1,264 @@
+// tests/rander_model.rs
+//
+// OBJ-Dateien laden und Meshes zeichnen
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use rander3d::math::{Transform, Vec3};
+use rander3d::rander::framebuffer::Framebuffer;
+use rander3d::rander::kamera::Camera;
+use rander3d::rander::offscreen::{render_scene, Scene, SceneObject};
+use rander3d::rander::rander_model::{load_model_materials, load_mtl, load_obj, CullMode, Material, Model, ShadingMode};
+
+const SIZE: usize = 32;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from("target/rander-test").join(name);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Quadrat in z = 0, Vorderseite zur Kamera (-z), alle Eckpunkte mit Normale `vn`
+fn quad(name: &str, vn: &str, shading: ShadingMode) -> Framebuffer {
+    let path = scratch_dir(name).join("quad.obj");
+    let obj = format!("v -1 -1 0\nv -1 1 0\nv 1 1 0\nv 1 -1 0\nvn {}\nf 1//1 2//1 3//1 4//1\n", vn);
+    fs::write(&path, obj).unwrap();
+    let mut model = load_obj(path.to_str().unwrap()).unwrap();
+    model.shading = shading;
+
+    let mut materials = HashMap::new();
+    materials.insert(String::new(), Material::default());
+    let scene = Scene {
+        objects: vec![SceneObject { model: &model, transform: Transform::new(Vec3::ZERO), materials: &materials }],
+        particles: Vec::new(),
+        light_dir: Vec3::new(0.0, 0.0, -1.0), // von der Kamera her
+        strake: 0.0,
+        overlay: None,
+    };
+
+    let mut camera = Camera::new(60.0, 1.0, 0.1, 100.0);
+    camera.position = Vec3::new(0.0, 0.0, -3.0);
+    camera.look_at(Vec3::ZERO);
+    let mut fb = Framebuffer::new(SIZE, SIZE);
+    render_scene(&scene, &camera, &mut fb);
+    fb
+}
+
+#[test]
+fn flat_shading_uses_the_plane_of_the_face() {
+    // Normalen aus der Datei zeigen zur Seite, die Fläche selbst zur Kamera
+    let sideways = quad("seitlich", "1 0 0", ShadingMode::Flat);
+    let matching = quad("passend", "0 0 -1", ShadingMode::Flat);
+    let center = SIZE / 2 * SIZE + SIZE / 2;
+
+    assert_eq!(sideways.buffer, matching.buffer);
+    assert!(sideways.buffer[center] & 0xFF > 200, "{:08x}", sideways.buffer[center]);
+
+    // Gouraud nimmt dagegen die Normalen der Eckpunkte: kein Licht
+    let smooth = quad("gouraud", "1 0 0", ShadingMode::Gouraud);
+    assert!(smooth.buffer[center] & 0xFF < 50, "{:08x}", smooth.buffer[center]);
+}
+
+/// Normale des Eckpunkts `corner` von Face `face`
+fn corner_normal(model: &Model, face: usize, corner: usize) -> Vec3 {
+    model.normals[model.faces[face].vertices[corner].normal_index.unwrap()]
+}
+
+#[test]
+fn obj_parser_reads_indices_groups_and_materials() {
+    let dir = scratch_dir("obj-parser");
+    fs::write(dir.join("teile.mtl"), "newmtl rot\nKd 1 0 0\n").unwrap();
+    let obj = "\
+mtllib teile.mtl
+v 0 0 0
+v 1 0 0
+v 0 1 0 0 1 0
+v 0 0 1
+vt 0.5 0.5
+usemtl rot
+o kiste
+g deckel
+s 1
+f 1/-1 2/-1 3/-1
+f -4 -1 -3
+g seite
+s 2
+f 1 3 4
+o platte
+s off
+f 2 3 4
+o kiste
+g deckel
+f 4 3 2
+";
+    let path = dir.join("teile.obj");
+    fs::write(&path, obj).unwrap();
+    let model = load_obj(path.to_str().unwrap()).unwrap();
+
+    // negative Indizes zählen vom zuletzt gelesenen Eintrag rückwärts
+    let indices = |face: usize| model.faces[face].vertices.iter().map(|fv| fv.vertex_index).collect::<Vec<_>>();
+    assert_eq!(indices(1), vec![0, 3, 1]);
+    assert_eq!(model.faces[0].vertices[2].texcoord_index, Some(0));
+
+    // gleiche Objekt/Gruppe-Paare werden zusammengeführt
+    let groups: Vec<_> = model.groups.iter().map(|g| (g.object.as_str(), g.name.as_str(), g.faces.clone())).collect();
+    assert_eq!(
+        groups,
+        vec![("kiste", "deckel", vec![0, 1, 4]), ("kiste", "seite", vec![2]), ("platte", "", vec![3])]
+    );
+    assert_eq!(model.faces[3].smoothing_group, 0);
+
+    // mtllib liegt neben der OBJ, nicht im Arbeitsverzeichnis
+    assert_eq!(model.material_libs, vec![dir.join("teile.mtl").to_string_lossy().to_string()]);
+    assert_eq!(model.faces[0].material, "rot");
+    assert_eq!(load_model_materials(&model).unwrap()["rot"].diffuse, Vec3::new(1.0, 0.0, 0.0));
+
+    // Vertices ohne Farbe werden vorne und hinten mit Weiß aufgefüllt
+    assert_eq!(model.colors, vec![Vec3::ONE, Vec3::ONE, Vec3::new(0.0, 1.0, 0.0), Vec3::ONE]);
+
+    // Ecke 1 liegt in drei Faces: Gruppe 1 mittelt +z und +y, Gruppe 2 bleibt bei +x
+    let diagonal = Vec3::new(0.0, 1.0, 1.0).normalize();
+    assert!((corner_normal(&model, 0, 0) - diagonal).length() < 1e-5);
+    assert!((corner_normal(&model, 1, 0) - diagonal).length() < 1e-5);
+    assert!((corner_normal(&model, 2, 0) - Vec3::new(1.0, 0.0, 0.0)).length() < 1e-5);
+    // ohne Glättung zählt nur die eigene Fläche
+    assert!((corner_normal(&model, 3, 0) - Vec3::ONE.normalize()).length() < 1e-5);
+}
+
+#[test]
+fn broken_obj_and_mtl_report_path_and_line() {
+    let dir = scratch_dir("kaputt");
+
+    // Index 5 bei nur drei Vertices
+    let obj = dir.join("kaputt.obj");
+    fs::write(&obj, "v 0 0 0\nv 1 0 0\nv 0 1 0\n# Kommentar\nf 1 2 5\n").unwrap();
+    let obj = obj.to_str().unwrap();
+    let err = load_obj(obj).err().unwrap();
+    assert_eq!((err.path.as_str(), err.line), (obj, Some(5)));
+
+    let mtl = dir.join("kaputt.mtl");
+    fs::write(&mtl, "newmtl rot\nKd 1 0 0\nNs viel\n").unwrap();
+    let mtl = mtl.to_str().unwrap();
+    let err = load_mtl(mtl).err().unwrap();
+    assert_eq!((err.path.as_str(), err.line), (mtl, Some(3)));
+
+    // map_Kd ohne Dateinamen sucht keine Textur namens "map_Kd"
+    fs::write(dir.join("ohne_textur.mtl"), "newmtl rot\nmap_Kd\n").unwrap();
+    let mtl = dir.join("ohne_textur.mtl");
+    let err = load_mtl(mtl.to_str().unwrap()).err().unwrap();
+    assert_eq!(err.line, Some(2));
+    assert_eq!(err.reason, "map_Kd braucht einen Dateinamen");
+}
+
+#[test]
+fn mtl_reads_every_field_and_keeps_defaults() {
+    let dir = scratch_dir("mtl-felder");
+    fs::write(dir.join("punkt.ppm"), "P3\n1 1\n255\n255 0 0\n").unwrap();
+    let mtl = "\
+newmtl voll
+Ka 0.1 0.2 0.3
+Kd 0.5
+Ks 1 1 0
+Ns 32
+Ke 0 0 0.25
+d 0.75
+illum 2
+map_Kd -s 1 1 1 punkt.ppm
+
+newmtl glas
+Tr 0.25
+
+newmtl leer
+";
+    let path = dir.join("felder.mtl");
+    fs::write(&path, mtl).unwrap();
+    let materials = load_mtl(path.to_str().unwrap()).unwrap();
+    assert_eq!(materials.len(), 3);
+
+    let voll = &materials["voll"];
+    assert_eq!(voll.ambient, Vec3::new(0.1, 0.2, 0.3));
+    assert_eq!(voll.diffuse, Vec3::splat(0.5));
+    assert_eq!(voll.specular, Vec3::new(1.0, 1.0, 0.0));
+    assert_eq!(voll.shininess, 32.0);
+    assert_eq!(voll.emission, Vec3::new(0.0, 0.0, 0.25));
+    assert_eq!(voll.opacity, 0.75);
+    assert_eq!(voll.illum, 2);
+    let texture = voll.texture.as_ref().unwrap();
+    assert_eq!((texture.width, texture.height), (1, 1));
+    assert_eq!(texture.pixels[0] & 0xFFFFFF, 0xFF0000);
+
+    // Tr ist Durchsichtigkeit, also umgekehrt zu d
+    assert_eq!(materials["glas"].opacity, 0.75);
+
+    // ohne Angaben gelten die Standardwerte
+    for name in ["glas", "leer"] {
+        let m = &materials[name];
+        assert_eq!(m.ambient, Vec3::ONE, "{}", name);
+        assert_eq!(m.diffuse, Vec3::ONE, "{}", name);
+        assert_eq!(m.specular, Vec3::ZERO, "{}", name);
+        assert_eq!(m.shininess, 1.0, "{}", name);
+        assert_eq!(m.emission, Vec3::ZERO, "{}", name);
+        assert_eq!(m.illum, 1, "{}", name);
+        assert!(m.texture.is_none(), "{}", name);
+    }
+    assert_eq!(materials["leer"].opacity, 1.0);
+}
+
+#[test]
+fn intersecting_quads_split_at_their_true_crossing() {
+    // rot steht in z = 0, blau ist gekippt (z = x) und sticht bei x = 0 hindurch
+    let path = scratch_dir("durchdringung").join("quads.obj");
+    let obj = "\
+v -1 -1 0
+v -1 1 0
+v 1 1 0
+v 1 -1 0
+v -1 -1 -1
+v -1 1 -1
+v 1 1 1
+v 1 -1 1
+usemtl rot
+f 1 2 3 4
+usemtl blau
+f 5 6 7 8
+";
+    fs::write(&path, obj).unwrap();
+    let mut model = load_obj(path.to_str().unwrap()).unwrap();
+    model.cull_mode = CullMode::None;
+
+    let mut materials = HashMap::new();
+    materials.insert("rot".to_string(), Material { diffuse: Vec3::new(1.0, 0.0, 0.0), ..Material::default() });
+    materials.insert("blau".to_string(), Material { diffuse: Vec3::new(0.0, 0.0, 1.0), ..Material::default() });
+    let scene = Scene {
+        objects: vec![SceneObject { model: &model, transform: Transform::new(Vec3::ZERO), materials: &materials }],
+        particles: Vec::new(),
+        light_dir: Vec3::new(0.0, 0.0, -1.0),
+        strake: 0.0,
+        overlay: None,
+    };
+
+    const WIDE: usize = 64;
+    let mut camera = Camera::new(60.0, 1.0, 0.1, 100.0);
+    camera.position = Vec3::new(0.0, 0.0, -3.0);
+    camera.look_at(Vec3::ZERO);
+    let mut fb = Framebuffer::new(WIDE, WIDE);
+    render_scene(&scene, &camera, &mut fb);
+
+    let row = WIDE / 2;
+    // (rot, blau) des Pixels in der mittleren Zeile
+    let channels = |x: usize| {
+        let c = fb.buffer[row * WIDE + x];
+        ((c >> 16) & 0xFF, c & 0xFF)
+    };
+    // links ist blau näher, rechts rot. Lineares z im Bild würde die Grenze
+    // nach links schieben: dort liegen die Spalten 28..31 knapp neben der Mitte.
+    for x in [8, 20, 28, 30] {
+        let (r, b) = channels(x);
+        assert!(b > r, "Spalte {}: {:08x}", x, fb.buffer[row * WIDE + x]);
+    }
+    for x in [33, 36, 44, 48] {
+        let (r, b) = channels(x);
+        assert!(r > b, "Spalte {}: {:08x}", x, fb.buffer[row * WIDE + x]);
+    }
+}
@@ -0,0 +1,182 @@
+// tests/spieler.rs
+//
+// Schwerkraft, Springen, Boden und Stufen für den Spieler
+
+use rander3d::logik::hitbox::{Collider, AABB};
+use rander3d::logik::spieler::{character_system, CharacterController};
+use rander3d::logik::welt::{Entity, World};
+use rander3d::math::{Quat, Transform, Vec3};
+
+const DT: f32 = 1.0 / 60.0;
+
+fn block(world: &mut World, name: &str, pos: Vec3, min: Vec3, max: Vec3) -> Entity {
+    let e = world.spawn(name);
+    world.transforms.insert(e, Transform::new(pos));
+    world.colliders.insert(e, Collider::Box(AABB { min, max }));
+    e
+}
+
+/// Boden mit Oberkante y = 0, Spieler (1 x 2 x 1, Füße bei y - 1) darüber
+fn setup(player_pos: Vec3) -> World {
+    let mut world = World::new(Vec3::UP);
+    block(&mut world, "boden", Vec3::ZERO, Vec3::new(-50.0, -1.0, -50.0), Vec3::new(50.0, 0.0, 50.0));
+
+    let player = world.player;
+    world.set_position(player, player_pos);
+    world.colliders.insert(player, Collider::Box(AABB { min: Vec3::new(-0.5, -1.0, -0.5), max: Vec3::new(0.5, 1.0, 0.5) }));
+    world.controllers.insert(player, CharacterController::new(player_pos));
+    world
+}
+
+fn run(world: &mut World, walk: Vec3, steps: usize) -> Vec<Entity> {
+    let mut hits = Vec::new();
+    for _ in 0..steps {
+        hits = character_system(world, world.player, walk, DT);
+    }
+    hits
+}
+
+fn grounded(world: &World) -> bool {
+    world.controllers.get(world.player).unwrap().grounded
+}
+
+#[test]
+fn falls_and_lands_on_ground() {
+    let mut world = setup(Vec3::new(0.0, 5.0, 0.0));
+    let hits = run(&mut world, Vec3::ZERO, 120);
+
+    assert!(grounded(&world));
+    assert!((world.position(world.player).y - 1.0).abs() < 0.01);
+    assert_eq!(world.names.get(hits[0]).unwrap(), "boden");
+
+    // auf dem Boden laufen bleibt möglich
+    run(&mut world, Vec3::new(0.1, 0.0, 0.0), 10);
+    assert!((world.position(world.player).x - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn jumps_only_from_ground() {
+    let mut world = setup(Vec3::new(0.0, 1.0, 0.0));
+    run(&mut world, Vec3::ZERO, 5);
+
+    world.controllers.get_mut(world.player).unwrap().jump();
+    run(&mut world, Vec3::ZERO, 10);
+    let high = world.position(world.player).y;
+    assert!(high > 1.5);
+    assert!(!grounded(&world));
+
+    // in der Luft kein zweiter Sprung
+    world.controllers.get_mut(world.player).unwrap().jump();
+    assert!(world.controllers.get(world.player).unwrap().velocity.y < 7.0);
+
+    run(&mut world, Vec3::ZERO, 120);
+    assert!(grounded(&world));
+}
+
+#[test]
+fn steps_up_small_ledges_but_not_walls() {
+    let mut world = setup(Vec3::new(0.0, 1.0, 0.0));
+    block(&mut world, "stufe", Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 0.0, -5.0), Vec3::new(5.0, 0.3, 5.0));
+    block(&mut world, "wand", Vec3::new(0.0, 0.0, 2.0), Vec3::new(-5.0, 0.0, 0.0), Vec3::new(-1.0, 3.0, 1.0));
+    run(&mut world, Vec3::ZERO, 5);
+
+    run(&mut world, Vec3::new(0.05, 0.0, 0.0), 60);
+    let pos = world.position(world.player);
+    assert!(pos.x > 2.5);
+    assert!((pos.y - 1.3).abs() < 0.01);
+
+    // zurück und gegen die hohe Wand
+    world.set_position(world.player, Vec3::new(-3.0, 1.0, 0.0));
+    run(&mut world, Vec3::new(0.0, 0.0, 0.05), 60);
+    assert!(world.position(world.player).z < 1.5);
+}
+
+#[test]
+fn respawns_after_falling_out() {
+    let mut world = setup(Vec3::new(0.0, 1.0, 0.0));
+    world.set_position(world.player, Vec3::new(100.0, 1.0, 0.0));
+    run(&mut world, Vec3::ZERO, 400);
+    assert!((world.position(world.player) - Vec3::new(0.0, 1.0, 0.0)).length() < 0.5);
+}
+
+#[test]
+fn slides_along_walls() {
+    let mut world = setup(Vec3::new(0.0, 1.0, 0.0));
+    let wall = block(&mut world, "wand", Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 0.0, -50.0), Vec3::new(1.0, 3.0, 50.0));
+    run(&mut world, Vec3::ZERO, 5);
+
+    // schräg gegen die Wand: bleibt davor, kommt aber in z voran
+    let hits = run(&mut world, Vec3::new(0.05, 0.0, 0.05), 60);
+    let pos = world.position(world.player);
+    assert!(pos.x < 1.5 && pos.x > 1.4);
+    assert!((pos.z - 3.0).abs() < 1e-3);
+    assert!(hits.contains(&wall));
+    assert!(grounded(&world));
+}
+
+#[test]
+fn rotated_box_blocks_with_its_real_shape() {
+    let mut world = setup(Vec3::new(-1.0, 1.0, 9.84));
+    // 4 x 4 Box um 45° gedreht: Raute mit Spitzen 2.83 vom Mittelpunkt
+    let diamond = block(&mut world, "raute", Vec3::new(0.0, 1.0, 5.0), Vec3::splat(-0.5), Vec3::splat(0.5));
+    *world.transforms.get_mut(diamond).unwrap() = Transform {
+        position: Vec3::new(0.0, 1.0, 5.0),
+        rotation: Quat::from_euler(0.0, std::f32::consts::FRAC_PI_4, 0.0),
+        scale: Vec3::splat(4.0),
+    };
+    run(&mut world, Vec3::ZERO, 5);
+
+    // schräg durch die Ecke der Hülle, an der Kante der Raute vorbei
+    let hits = run(&mut world, Vec3::new(0.05, 0.0, -0.05), 120);
+    let pos = world.position(world.player);
+    assert!((pos - Vec3::new(5.0, 1.0, 3.84)).length() < 1e-3, "{:?}", pos);
+    assert!(!hits.contains(&diamond));
+
+    // gerade auf die Spitze zu: hält kurz davor an
+    world.set_position(world.player, Vec3::new(-6.0, 1.0, 5.0));
+    let hits = run(&mut world, Vec3::new(0.05, 0.0, 0.0), 60);
+    let x = world.position(world.player).x;
+    assert!(x < -3.32 && x > -3.4, "{}", x);
+    assert!(hits.contains(&diamond));
+}
+
+#[test]
+fn walks_past_a_sphere_through_its_hull() {
+    let mut world = setup(Vec3::new(-1.0, 1.0, 10.4));
+    let ball = world.spawn("kugel");
+    world.transforms.insert(ball, Transform::new(Vec3::new(0.0, 1.0, 5.0)));
+    world.colliders.insert(ball, Collider::Sphere { center: Vec3::ZERO, radius: 2.0 });
+    run(&mut world, Vec3::ZERO, 5);
+
+    // die Ecke der Hülle streifen, die Kugel selbst nicht: kein Halt, kein Kontakt
+    for _ in 0..120 {
+        let hits = run(&mut world, Vec3::new(0.05, 0.0, -0.05), 1);
+        assert!(!hits.contains(&ball));
+    }
+    let pos = world.position(world.player);
+    assert!((pos - Vec3::new(5.0, 1.0, 4.4)).length() < 1e-3, "{:?}", pos);
+
+    // dagegen laufen zählt
+    world.set_position(world.player, Vec3::new(-5.0, 1.0, 5.0));
+    let hits = run(&mut world, Vec3::new(0.05, 0.0, 0.0), 60);
+    assert!((world.position(world.player).x + 2.5).abs() < 0.01);
+    assert!(hits.contains(&ball));
+}
+
+#[test]
+fn lands_on_the_real_surface_of_triangles() {
+    let mut world = setup(Vec3::new(0.0, 4.0, 0.0));
+    // Rampe von y = 0 bei x = -2 bis y = 1 bei x = 2, Hülle reicht bis y = 1
+    let (a, b) = (Vec3::new(-2.0, 0.0, -2.0), Vec3::new(2.0, 1.0, -2.0));
+    let (c, d) = (Vec3::new(2.0, 1.0, 2.0), Vec3::new(-2.0, 0.0, 2.0));
+    let ramp = world.spawn("rampe");
+    world.transforms.insert(ramp, Transform::new(Vec3::ZERO));
+    world.colliders.insert(ramp, Collider::Mesh(vec![[a, b, c], [a, c, d]]));
+
+    let hits = run(&mut world, Vec3::ZERO, 120);
+    assert!(grounded(&world));
+    assert!(hits.contains(&ramp));
+    // Füße auf der höchsten Stelle unter dem Spieler (x = 0.5), nicht auf der Hülle
+    let y = world.position(world.player).y;
+    assert!((y - 1.625).abs() < 0.01, "{}", y);
+}
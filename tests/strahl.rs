@@ -0,0 +1,119 @@
+// tests/strahl.rs
+//
+// Strahlen: gegen Box, Dreieck und Formen, in der Welt, aus Pixeln
+
+use rander3d::logik::hitbox::{Collider, AABB};
+use rander3d::logik::strahl::*;
+use rander3d::logik::welt::{broadphase_system, World};
+use rander3d::math::{Quat, Transform, Vec3};
+use rander3d::rander::kamera::Camera;
+
+fn unit() -> AABB {
+    AABB { min: Vec3::splat(-0.5), max: Vec3::splat(0.5) }
+}
+
+#[test]
+fn ray_against_box_and_triangle() {
+    let ray = Ray::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    let (dist, normal) = ray_aabb(&ray, &unit()).unwrap();
+    assert!((dist - 4.5).abs() < 1e-6);
+    assert_eq!(normal, Vec3::new(-1.0, 0.0, 0.0));
+
+    // vorbei, dahinter und von innen
+    assert!(ray_aabb(&Ray::new(Vec3::new(-5.0, 1.0, 0.0), Vec3::new(1.0, 0.0, 0.0)), &unit()).is_none());
+    assert!(ray_aabb(&Ray::new(Vec3::new(5.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0)), &unit()).is_none());
+    assert!(ray_aabb(&Ray::new(Vec3::ZERO, Vec3::new(1.0, 0.0, 0.0)), &unit()).is_none());
+
+    let tri = [Vec3::new(-1.0, 0.0, -1.0), Vec3::new(1.0, 0.0, -1.0), Vec3::new(0.0, 0.0, 1.0)];
+    let down = Ray::new(Vec3::new(0.0, 3.0, 0.0), -Vec3::UP);
+    let (dist, normal) = ray_triangle(&down, &tri).unwrap();
+    assert!((dist - 3.0).abs() < 1e-6);
+    assert_eq!(normal, Vec3::UP);
+    // von unten zeigt die Normale nach unten
+    let up = Ray::new(Vec3::new(0.0, -3.0, 0.0), Vec3::UP);
+    assert_eq!(ray_triangle(&up, &tri).unwrap().1, -Vec3::UP);
+    assert!(ray_triangle(&Ray::new(Vec3::new(0.9, 3.0, 0.9), -Vec3::UP), &tri).is_none());
+}
+
+#[test]
+fn ray_against_transformed_shapes() {
+    let ray = Ray::new(Vec3::new(-10.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+
+    // Box mit Skalierung 4: Eintritt bei x = -2
+    let big = Transform { scale: Vec3::splat(4.0), ..Transform::default() };
+    let (dist, normal) = ray_collider(&ray, &Collider::Box(unit()), &big).unwrap();
+    assert!((dist - 8.0).abs() < 1e-4);
+    assert!((normal - Vec3::new(-1.0, 0.0, 0.0)).length() < 1e-5);
+
+    // um 45° gedreht: Spitze bei x = -0.707
+    let turned = Transform { rotation: Quat::from_euler(0.0, std::f32::consts::FRAC_PI_4, 0.0), ..Transform::default() };
+    let (dist, _) = ray_collider(&ray, &Collider::Box(unit()), &turned).unwrap();
+    assert!((dist - (10.0 - 0.5 * 2f32.sqrt())).abs() < 1e-4);
+
+    let ball = Collider::Sphere { center: Vec3::ZERO, radius: 1.0 };
+    let (dist, normal) = ray_collider(&ray, &ball, &Transform::new(Vec3::new(0.0, 0.0, 0.0))).unwrap();
+    assert!((dist - 9.0).abs() < 1e-5);
+    assert!((normal - Vec3::new(-1.0, 0.0, 0.0)).length() < 1e-5);
+
+    // senkrechte Kapsel: seitlich auf den Mantel, von oben auf die Halbkugel
+    let stick = Collider::Capsule { a: Vec3::new(0.0, -1.0, 0.0), b: Vec3::new(0.0, 1.0, 0.0), radius: 0.5 };
+    let side = Ray::new(Vec3::new(-10.0, 0.5, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    assert!((ray_collider(&side, &stick, &Transform::default()).unwrap().0 - 9.5).abs() < 1e-5);
+    let top = Ray::new(Vec3::new(0.0, 10.0, 0.0), -Vec3::UP);
+    let (dist, normal) = ray_collider(&top, &stick, &Transform::default()).unwrap();
+    assert!((dist - 8.5).abs() < 1e-5);
+    assert!((normal - Vec3::UP).length() < 1e-5);
+}
+
+#[test]
+fn raycast_finds_nearest_entity() {
+    let mut world = World::new(Vec3::UP);
+    let mut block = |name: &str, x: f32| {
+        let e = world.spawn(name);
+        world.transforms.insert(e, Transform::new(Vec3::new(x, 0.0, 0.0)));
+        world.colliders.insert(e, Collider::Box(unit()));
+        e
+    };
+    let near = block("nah", 3.0);
+    block("fern", 6.0);
+    // der Spieler steckt selbst in einer Hitbox
+    world.colliders.insert(world.player, Collider::Box(unit()));
+    broadphase_system(&mut world);
+
+    let ray = Ray::new(Vec3::ZERO, Vec3::new(1.0, 0.0, 0.0));
+    let hit = raycast(&world, &ray, 100.0).unwrap();
+    assert_eq!(hit.entity, near);
+    assert!((hit.distance - 2.5).abs() < 1e-6);
+    assert!((hit.point - Vec3::new(2.5, 0.0, 0.0)).length() < 1e-6);
+    assert_eq!(hit.normal, Vec3::new(-1.0, 0.0, 0.0));
+
+    assert!(raycast(&world, &ray, 2.0).is_none());
+}
+
+#[test]
+fn screen_ray_matches_projection() {
+    let mut camera = Camera::new(60.0, 640.0 / 360.0, 0.1, 1000.0);
+    camera.position = Vec3::new(1.0, 2.0, -3.0);
+    camera.yaw = 0.4;
+    camera.pitch = -0.2;
+
+    // Punkt projizieren, dann durch seinen Pixel zurück
+    let target = Vec3::new(3.0, 1.0, 6.0);
+    let (sx, sy) = camera.to_screen(camera.to_clip(camera.view().transform_point(target)), 640, 360);
+    let ray = Ray::from_screen(&camera, sx, sy, 640, 360);
+    let to_target = (target - camera.position).normalize();
+    assert!((ray.dir - to_target).length() < 1e-4);
+    assert_eq!(ray.origin, camera.position);
+
+    // Bildmitte = Blickrichtung
+    let center = Ray::from_screen(&camera, 320.0, 180.0, 640, 360);
+    assert!((center.dir - camera.forward()).length() < 1e-4);
+
+    let mut world = World::new(Vec3::UP);
+    let e = world.spawn("ziel");
+    world.transforms.insert(e, Transform::new(target));
+    world.colliders.insert(e, Collider::Sphere { center: Vec3::ZERO, radius: 0.5 });
+    broadphase_system(&mut world);
+    assert_eq!(pick(&world, &camera, sx, sy, 640, 360).unwrap().entity, e);
+    assert!(pick(&world, &camera, 0.0, 0.0, 640, 360).is_none());
+}
@@ -0,0 +1,102 @@
+// tests/szene.rs
+//
+// Szenen-Dateien laden: mitgelieferte Szene, eigene Assets, Fehlerfälle
+
+use std::fs;
+use std::path::PathBuf;
+use rander3d::logik::hitbox::{Collider, AABB};
+use rander3d::logik::szene::{load_scene, EmitterTrigger};
+use rander3d::math::Vec3;
+use rander3d::rander::rander_model::{CullMode, ShadingMode};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from("target/szene-test").join(name);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn shipped_scene_loads() {
+    let (world, _) = load_scene("assets/scene.ron").unwrap();
+
+    let names: Vec<&str> = world.names.iter().map(|(_, n)| n.as_str()).collect();
+    assert_eq!(names, ["player", "cube", "plate", "trasch"]);
+    let plate = world.find("plate").unwrap();
+    let trasch = world.find("trasch").unwrap();
+    assert_eq!(world.meshes.get(plate).unwrap().model.cull_mode, CullMode::None);
+    assert_eq!(world.meshes.get(trasch).unwrap().model.shading, ShadingMode::Phong);
+    assert_eq!(world.emitters.get(world.player).unwrap().desc.trigger, EmitterTrigger::Fire);
+    // alte Projektions-Teiler (15) sind Objektgröße 1, wie die alten Hitboxen
+    let cube = world.find("cube").unwrap();
+    assert_eq!(world.transforms.get(cube).unwrap().scale, Vec3::ONE);
+    assert_eq!(world.transforms.get(trasch).unwrap().scale, Vec3::ONE);
+}
+
+#[test]
+fn entities_from_scene_file() {
+    let dir = scratch_dir("entities");
+    fs::write(dir.join("tri.obj"), "v 0 0 0\nv 2 0 0\nv 0 1 3\nf 1 2 3\n").unwrap();
+    fs::write(
+        dir.join("scene.ron"),
+        r#"#![enable(implicit_some)]
+(
+    light_dir: (0.0, 1.0, 0.0),
+    player: (position: (1.0, 2.0, 3.0), hitbox: Box(min: (-1.0, -1.0, -1.0), max: (1.0, 1.0, 1.0))),
+    entities: [
+        (
+            name: "tri",
+            mesh: "tri.obj",
+            transform: (position: (5.0, 0.0, 0.0), scale: (2.0, 2.0, 2.0)),
+            color: (255, 0, 0),
+            hitbox: Mesh,
+            emitter: (trigger: Collision, count: 3, life: 1.0, slowness: (1.0, 1.0, 1.0), color: (0, 0, 0)),
+        ),
+        (name: "fehlt", mesh: "fehlt.obj", hitbox: Mesh),
+        (name: "genau", mesh: "tri.obj", hitbox: Triangles),
+        (name: "rund", mesh: "tri.obj", hitbox: Sphere(center: (0.0, 1.0, 0.0), radius: 2.0)),
+    ],
+)"#,
+    )
+    .unwrap();
+
+    let (mut world, warnings) = load_scene(dir.join("scene.ron").to_str().unwrap()).unwrap();
+
+    // nur das fehlende Mesh wird gemeldet
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].path.ends_with("fehlt.obj"));
+
+    assert_eq!(world.position(world.player), Vec3::new(1.0, 2.0, 3.0));
+    assert!(world.colliders.get(world.player).is_some());
+
+    let tri = world.find("tri").unwrap();
+    let transform = world.transforms.get(tri).unwrap();
+    assert_eq!(transform.position, Vec3::new(5.0, 0.0, 0.0));
+    assert_eq!(transform.scale, Vec3::splat(2.0));
+    assert_eq!(world.meshes.get(tri).unwrap().materials[""].diffuse, Vec3::new(1.0, 0.0, 0.0));
+    let hitbox = world.colliders.get(tri).unwrap();
+    assert_eq!(*hitbox, Collider::Box(AABB { min: Vec3::ZERO, max: Vec3::new(2.0, 1.0, 3.0) }));
+
+    let genau = world.colliders.get(world.find("genau").unwrap()).unwrap();
+    assert!(matches!(genau, Collider::Mesh(tris) if tris.len() == 1));
+    let rund = world.colliders.get(world.find("rund").unwrap()).unwrap();
+    assert_eq!(*rund, Collider::Sphere { center: Vec3::UP, radius: 2.0 });
+
+    let emitter = world.emitters.get_mut(tri).unwrap();
+    emitter.emit(Vec3::ONE);
+    assert_eq!(emitter.particles.len(), 3);
+
+    // Ersatz-Würfel: Fehlerfarbe, keine Hitbox
+    let missing = world.find("fehlt").unwrap();
+    assert!(world.meshes.get(missing).unwrap().materials.is_empty());
+    assert!(world.colliders.get(missing).is_none());
+}
+
+#[test]
+fn broken_scene_reports_line() {
+    let dir = scratch_dir("broken");
+    let path = dir.join("scene.ron");
+    fs::write(&path, "(\n    light_dir: (0.0, 1.0, 0.0),\n    player: (position: (0.0, 0.0)),\n)\n").unwrap();
+
+    let err = load_scene(path.to_str().unwrap()).err().unwrap();
+    assert_eq!(err.line, Some(3));
+}
@@ -0,0 +1,135 @@
+// tests/welt.rs
+//
+// Objekt-Welt und Systeme: Kollision, Partikel, Rendern
+
+use rander3d::logik::hitbox::{Collider, AABB};
+use rander3d::logik::szene::{EmitterDesc, EmitterTrigger};
+use rander3d::logik::welt::*;
+use rander3d::math::{Transform, Vec3};
+use rander3d::rander::framebuffer::Framebuffer;
+use rander3d::rander::kamera::Camera;
+use rander3d::rander::offscreen::{render_scene, Scene};
+use rander3d::rander::rander_model::placeholder_model;
+use std::collections::HashMap;
+
+fn unit_box() -> Collider {
+    Collider::Box(AABB { min: Vec3::splat(-0.5), max: Vec3::splat(0.5) })
+}
+
+fn emitter(trigger: EmitterTrigger) -> ParticleEmitter {
+    ParticleEmitter::new(EmitterDesc {
+        trigger,
+        count: 4,
+        life: 1.0,
+        offset: [0.0; 3],
+        slowness: [1.0; 3],
+        color: [255, 255, 255],
+    })
+}
+
+/// Viele Kisten in einer Reihe, Abstand 2
+fn row_of_boxes(world: &mut World, count: usize) -> Vec<Entity> {
+    (0..count)
+        .map(|i| {
+            let e = world.spawn(&format!("kiste{}", i));
+            world.transforms.insert(e, Transform::new(Vec3::new(i as f32 * 2.0, 0.0, 0.0)));
+            world.colliders.insert(e, unit_box());
+            world.emitters.insert(e, emitter(EmitterTrigger::Collision));
+            e
+        })
+        .collect()
+}
+
+#[test]
+fn collision_finds_touching_entities() {
+    let mut world = World::new(Vec3::UP);
+    let boxes = row_of_boxes(&mut world, 300);
+    world.colliders.insert(world.player, unit_box());
+    broadphase_system(&mut world);
+
+    world.set_position(world.player, Vec3::new(401.0, 0.0, 0.0));
+    assert_eq!(collision_system(&world, world.player), [boxes[200], boxes[201]]);
+
+    world.set_position(world.player, Vec3::new(0.0, 5.0, 0.0));
+    assert!(collision_system(&world, world.player).is_empty());
+
+    // ohne Transform nicht in der Welt
+    world.transforms.remove(boxes[0]);
+    world.set_position(world.player, Vec3::ZERO);
+    assert!(collision_system(&world, world.player).is_empty());
+}
+
+#[test]
+fn emitters_fire_only_on_their_trigger() {
+    let mut world = World::new(Vec3::UP);
+    let boxes = row_of_boxes(&mut world, 3);
+    world.emitters.insert(world.player, emitter(EmitterTrigger::Fire));
+
+    emit_system(&mut world, EmitterTrigger::Collision, Some(&boxes[1..2]));
+    let counts: Vec<usize> = world.emitters.iter().map(|(_, e)| e.particles.len()).collect();
+    assert_eq!(counts, [0, 0, 4, 0]);
+    assert_eq!(world.emitters.get(boxes[1]).unwrap().particles[0].pos, Vec3::new(2.0, 0.0, 0.0));
+
+    emit_system(&mut world, EmitterTrigger::Fire, None);
+    assert_eq!(world.emitters.get(world.player).unwrap().particles.len(), 4);
+}
+
+#[test]
+fn render_system_collects_meshes() {
+    let mut world = World::new(Vec3::UP);
+    let boxes = row_of_boxes(&mut world, 2);
+    for &e in &boxes {
+        world.meshes.insert(e, MeshRenderer { model: placeholder_model(), materials: HashMap::new() });
+    }
+    world.despawn(boxes[0]);
+
+    let scene = render_system(&world, 0.5);
+    assert_eq!(scene.objects.len(), 1);
+    assert_eq!(scene.objects[0].transform.position, Vec3::new(2.0, 0.0, 0.0));
+    assert!(world.find("kiste0").is_none());
+}
+
+#[test]
+fn render_scene_draws_particles_and_overlay() {
+    let mut world = World::new(Vec3::UP);
+    let boxes = row_of_boxes(&mut world, 1);
+    emit_system(&mut world, EmitterTrigger::Collision, Some(&boxes));
+
+    let mut camera = Camera::new(60.0, 64.0 / 48.0, 0.1, 100.0);
+    camera.position = Vec3::new(0.0, 0.0, -5.0);
+    camera.look_at(Vec3::ZERO);
+    let draw = |scene: &Scene| {
+        let mut fb = Framebuffer::new(64, 48);
+        render_scene(scene, &camera, &mut fb);
+        fb.buffer
+    };
+
+    let mut scene = render_system(&world, 0.5);
+    assert_eq!(scene.particles.len(), 1);
+    let with_particles = draw(&scene);
+    scene.particles.clear();
+    let sky = draw(&scene);
+    assert_ne!(with_particles[24 * 64 + 32], sky[24 * 64 + 32]);
+
+    // Text oben links, wie die FPS im Fenster
+    scene.overlay = Some("FPS: 0".to_string());
+    let text = draw(&scene);
+    assert!(text[10 * 64..20 * 64].contains(&0xFFFFFF));
+    assert_eq!(text[30 * 64..], sky[30 * 64..]);
+}
+
+#[test]
+fn collision_uses_scale_of_transform() {
+    let mut world = World::new(Vec3::UP);
+    let cube = world.spawn("cube");
+    world.transforms.insert(cube, Transform { scale: Vec3::splat(15.0), ..Transform::default() });
+    world.colliders.insert(cube, unit_box());
+    world.colliders.insert(world.player, unit_box());
+    broadphase_system(&mut world);
+
+    // ohne Skalierung wäre die Box bei 5 weit weg, mit 15 steckt man drin
+    world.set_position(world.player, Vec3::new(5.0, 0.0, 0.0));
+    assert_eq!(collision_system(&world, world.player), [cube]);
+    world.set_position(world.player, Vec3::new(8.5, 0.0, 0.0));
+    assert!(collision_system(&world, world.player).is_empty());
+}
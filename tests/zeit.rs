@@ -0,0 +1,98 @@
+// tests/zeit.rs
+//
+// Fester Zeitschritt: gleiche Simulation unabhängig von der Bildrate
+
+use std::f32::consts::PI;
+use rander3d::logik::zeit::FixedStep;
+use rander3d::math::Vec3;
+use rander3d::rander::kamera::Camera;
+use rander3d::rander::partikel::{update_partikel, Particle};
+
+#[test]
+fn fixed_step_counts_whole_steps() {
+    let mut clock = FixedStep::new(60.0);
+
+    // 2,5 Schritte: zwei laufen, der Rest wartet
+    assert_eq!(clock.advance_by(2.5 / 60.0), 2);
+    assert!((clock.alpha() - 0.5).abs() < 1e-3);
+    assert_eq!(clock.advance_by(0.6 / 60.0), 1);
+
+    // Hänger werden gekappt statt nachgeholt
+    assert_eq!(clock.advance_by(10.0), (0.25 * 60.0) as u32);
+}
+
+#[test]
+fn clamped_frame_keeps_leftover() {
+    // 0,3 s wird auf 0,25 s gekappt: 15 Schritte, Rest bleibt unter einem Schritt
+    let mut clock = FixedStep::new(60.0);
+    assert_eq!(clock.advance_by(0.3), 15);
+    assert!(clock.alpha() < 1.0);
+
+    // angefangener Schritt von vorher kommt zur gekappten Zeit dazu
+    let mut clock = FixedStep::new(60.0);
+    assert_eq!(clock.advance_by(0.7 / 60.0), 0);
+    assert_eq!(clock.advance_by(0.3), 15);
+    assert!((clock.alpha() - 0.7).abs() < 1e-3);
+    assert_eq!(clock.advance_by(0.4 / 60.0), 1);
+    assert!((clock.alpha() - 0.1).abs() < 1e-3);
+}
+
+#[test]
+fn same_steps_at_any_frame_rate() {
+    let mut slow = FixedStep::new(60.0);
+    let mut fast = FixedStep::new(60.0);
+
+    let slow_steps: u32 = (0..30).map(|_| slow.advance_by(1.0 / 30.0)).sum();
+    let fast_steps: u32 = (0..240).map(|_| fast.advance_by(1.0 / 240.0)).sum();
+    assert!((slow_steps as i32 - 60).abs() <= 1);
+    assert!((fast_steps as i32 - 60).abs() <= 1);
+}
+
+#[test]
+fn particles_move_by_time_not_calls() {
+    let start = || vec![Particle { pos: Vec3::ZERO, vel: Vec3::new(1.0, 0.0, 0.0), life: 20.0 }];
+    let camera = Camera::new(60.0, 1.0, 0.1, 100.0);
+
+    let mut once = start();
+    update_partikel(&mut once, (10.0, 10.0, 10.0), &camera, 0.1);
+
+    let mut many = start();
+    for _ in 0..10 {
+        update_partikel(&mut many, (10.0, 10.0, 10.0), &camera, 0.01);
+    }
+
+    assert!((once[0].pos - many[0].pos).length() < 1e-4);
+    assert!((once[0].life - many[0].life).abs() < 1e-4);
+    // 0,6 Einheiten pro Sekunde bei vel 1 / 10 und 60 Referenz-FPS
+    assert!((once[0].pos.x - 0.6).abs() < 1e-4);
+
+    update_partikel(&mut once, (10.0, 10.0, 10.0), &camera, 10.0);
+    assert!(once.is_empty());
+}
+
+#[test]
+fn only_visible_far_particles_fade_faster() {
+    // Kamera im Ursprung schaut nach +z, beide 50 weit weg
+    let camera = Camera::new(60.0, 1.0, 0.1, 100.0);
+    let mut particles = vec![
+        Particle { pos: Vec3::new(0.0, 0.0, 50.0), vel: Vec3::ZERO, life: 20.0 },
+        Particle { pos: Vec3::new(0.0, 0.0, -50.0), vel: Vec3::ZERO, life: 20.0 },
+    ];
+    update_partikel(&mut particles, (1.0, 1.0, 1.0), &camera, 1.0 / 60.0);
+
+    assert!((particles[0].life - 19.75).abs() < 1e-4);
+    assert!((particles[1].life - 19.95).abs() < 1e-4);
+}
+
+#[test]
+fn camera_lerp_takes_short_way_round() {
+    let mut a = Camera::new(60.0, 1.0, 0.1, 100.0);
+    let mut b = a;
+    a.yaw = PI - 0.1;
+    b.yaw = -PI + 0.1;
+    b.position = Vec3::new(2.0, 0.0, 0.0);
+
+    let mid = a.lerp(&b, 0.5);
+    assert!((mid.yaw.abs() - PI).abs() < 1e-4);
+    assert_eq!(mid.position, Vec3::new(1.0, 0.0, 0.0));
+}